@@ -10,9 +10,10 @@ fn main() {
     println!("Test 1: Empty session ID");
     let json = r#"{"session_id": ""}"#;
     let result: Result<StartSearchResponse, _> = serde_json::from_str(json);
-    match result {
-        Ok(_) => println!("  ❌ FAILED: Should have rejected empty session_id"),
-        Err(e) => println!("  ✅ PASSED: Rejected with error: {}", e),
+    match result.map(|resp| resp.validate()) {
+        Ok(Ok(())) => println!("  ❌ FAILED: Should have rejected empty session_id"),
+        Ok(Err(e)) => println!("  ✅ PASSED: Rejected with error: {}", e),
+        Err(e) => println!("  ❌ FAILED: Deserialization failed unexpectedly. Error: {}", e),
     }
     
     // Test 2: Valid session ID should succeed
@@ -28,9 +29,10 @@ fn main() {
     println!("\nTest 3: Negative PID");
     let json = r#"{"pid": -1}"#;
     let result: Result<StartTerminalCommandResponse, _> = serde_json::from_str(json);
-    match result {
-        Ok(_) => println!("  ❌ FAILED: Should have rejected negative PID"),
-        Err(e) => println!("  ✅ PASSED: Rejected with error: {}", e),
+    match result.map(|resp| resp.validate()) {
+        Ok(Ok(())) => println!("  ❌ FAILED: Should have rejected negative PID"),
+        Ok(Err(e)) => println!("  ✅ PASSED: Rejected with error: {}", e),
+        Err(e) => println!("  ❌ FAILED: Deserialization failed unexpectedly. Error: {}", e),
     }
     
     // Test 4: Valid positive PID should succeed
@@ -46,27 +48,30 @@ fn main() {
     println!("\nTest 5: Zero PID");
     let json = r#"{"pid": 0}"#;
     let result: Result<StartTerminalCommandResponse, _> = serde_json::from_str(json);
-    match result {
-        Ok(_) => println!("  ❌ FAILED: Should have rejected zero PID"),
-        Err(e) => println!("  ✅ PASSED: Rejected with error: {}", e),
+    match result.map(|resp| resp.validate()) {
+        Ok(Ok(())) => println!("  ❌ FAILED: Should have rejected zero PID"),
+        Ok(Err(e)) => println!("  ✅ PASSED: Rejected with error: {}", e),
+        Err(e) => println!("  ❌ FAILED: Deserialization failed unexpectedly. Error: {}", e),
     }
     
     // Test 6: Zero GitHub user ID should fail
     println!("\nTest 6: Zero GitHub user ID");
     let json = r#"{"id": 0, "login": "user"}"#;
     let result: Result<GitHubUser, _> = serde_json::from_str(json);
-    match result {
-        Ok(_) => println!("  ❌ FAILED: Should have rejected zero user ID"),
-        Err(e) => println!("  ✅ PASSED: Rejected with error: {}", e),
+    match result.map(|resp| resp.validate()) {
+        Ok(Ok(())) => println!("  ❌ FAILED: Should have rejected zero user ID"),
+        Ok(Err(e)) => println!("  ✅ PASSED: Rejected with error: {}", e),
+        Err(e) => println!("  ❌ FAILED: Deserialization failed unexpectedly. Error: {}", e),
     }
     
     // Test 7: Empty GitHub user login should fail
     println!("\nTest 7: Empty GitHub user login");
     let json = r#"{"id": 123, "login": ""}"#;
     let result: Result<GitHubUser, _> = serde_json::from_str(json);
-    match result {
-        Ok(_) => println!("  ❌ FAILED: Should have rejected empty login"),
-        Err(e) => println!("  ✅ PASSED: Rejected with error: {}", e),
+    match result.map(|resp| resp.validate()) {
+        Ok(Ok(())) => println!("  ❌ FAILED: Should have rejected empty login"),
+        Ok(Err(e)) => println!("  ✅ PASSED: Rejected with error: {}", e),
+        Err(e) => println!("  ❌ FAILED: Deserialization failed unexpectedly. Error: {}", e),
     }
     
     // Test 8: Valid GitHub user should succeed
@@ -82,9 +87,10 @@ fn main() {
     println!("\nTest 9: Empty string in session_ids array");
     let json = r#"{"session_ids": ["valid-id", "", "another-id"], "worker_count": 3}"#;
     let result: Result<SpawnClaudeAgentResponse, _> = serde_json::from_str(json);
-    match result {
-        Ok(_) => println!("  ❌ FAILED: Should have rejected empty string in array"),
-        Err(e) => println!("  ✅ PASSED: Rejected with error: {}", e),
+    match result.map(|resp| resp.validate()) {
+        Ok(Ok(())) => println!("  ❌ FAILED: Should have rejected empty string in array"),
+        Ok(Err(e)) => println!("  ✅ PASSED: Rejected with error: {}", e),
+        Err(e) => println!("  ❌ FAILED: Deserialization failed unexpectedly. Error: {}", e),
     }
     
     // Test 10: Count mismatch should fail validation
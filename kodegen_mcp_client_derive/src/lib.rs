@@ -0,0 +1,315 @@
+//! Derive macros for `kodegen_mcp_client`
+//!
+//! Use through the re-export in the main crate:
+//!
+//! ```ignore
+//! use kodegen_mcp_client::validation::Validate;
+//!
+//! #[derive(Deserialize, Validate)]
+//! pub struct GitHubIssuesResponse {
+//!     pub count: u64,
+//!     #[validate(len_eq = "count", nested)]
+//!     pub issues: Vec<GitHubIssue>,
+//! }
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::{Group, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
+use syn::{
+    Data, DeriveInput, Expr, Field, Fields, Ident, LitStr, Path, Token, parse_macro_input,
+    spanned::Spanned, token,
+};
+
+/// Derive `kodegen_mcp_client::validation::Validate` from field attributes
+///
+/// Supported field rules (several may be combined on one field):
+///
+/// - `#[validate(len_eq = "count")]`: `field.len()` must equal the `count` field,
+///   reported with `count_mismatch_error`
+/// - `#[validate(non_empty)]`: `field.is_empty()` must be false (strings, vectors)
+/// - `#[validate(range(min = 1, max = 100))]`: inclusive bounds, either optional
+/// - `#[validate(custom = "path::to_fn")]`: calls `fn(&FieldType) -> Result<(), String>`
+/// - `#[validate(nested)]`: validates the field's own `Validate` impl (structs,
+///   `Vec`, `Option`), reporting its failures under the field's path
+///
+/// Every rule is checked and all failures are collected. Each failure is
+/// recorded under the field's JSON pointer, honoring `#[serde(rename = "...")]`
+/// and the container's `#[serde(rename_all = "...")]`.
+/// A struct without any `#[validate]` attributes gets an implementation that
+/// always succeeds.
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_validate(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_validate(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields.named.iter().collect(),
+            Fields::Unit => Vec::new(),
+            Fields::Unnamed(_) => {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "#[derive(Validate)] requires named fields",
+                ));
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "#[derive(Validate)] only supports structs",
+            ));
+        }
+    };
+
+    let rename_all = rename_all(input)?;
+    let mut checks = Vec::new();
+    for field in fields {
+        let json_name = json_name(field, rename_all)?;
+        for rule in field_rules(field)? {
+            checks.push(rule.expand(field, &json_name));
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::kodegen_mcp_client::validation::Validate for #name #ty_generics #where_clause {
+            fn validate_into(&self, errors: &mut ::kodegen_mcp_client::validation::ValidationErrors) {
+                #(#checks)*
+            }
+        }
+    })
+}
+
+/// A single `#[validate(...)]` rule on a field
+enum Rule {
+    LenEq(Ident),
+    NonEmpty,
+    Range {
+        min: Option<Box<Expr>>,
+        max: Option<Box<Expr>>,
+    },
+    Custom(Path),
+    Nested,
+}
+
+/// Case convention from a container's `#[serde(rename_all = "...")]`
+#[derive(Clone, Copy)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_name(name: &LitStr) -> syn::Result<Self> {
+        Ok(match name.value().as_str() {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+            _ => return Err(syn::Error::new(name.span(), "unknown rename_all rule")),
+        })
+    }
+
+    /// Rename a snake_case field the way serde does
+    fn apply(self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_string(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal | Self::Camel => {
+                let mut renamed = String::with_capacity(field.len());
+                let mut capitalize = matches!(self, Self::Pascal);
+                for c in field.chars() {
+                    if c == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        renamed.push(c.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        renamed.push(c);
+                    }
+                }
+                renamed
+            }
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => field.replace('_', "-").to_ascii_uppercase(),
+        }
+    }
+}
+
+/// Visit the `rename`-style entries of every `#[serde(...)]` attribute
+///
+/// `on_rename` gets the name used when deserializing, from either
+/// `key = "..."` or `key(deserialize = "...")`; other entries are skipped.
+fn serde_renames(
+    attrs: &[syn::Attribute],
+    key: &str,
+    mut on_rename: impl FnMut(LitStr) -> syn::Result<()>,
+) -> syn::Result<()> {
+    for attr in attrs.iter().filter(|a| a.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(key) && meta.input.peek(Token![=]) {
+                on_rename(meta.value()?.parse()?)?;
+            } else if meta.path.is_ident(key) && meta.input.peek(token::Paren) {
+                meta.parse_nested_meta(|direction| {
+                    let name: LitStr = direction.value()?.parse()?;
+                    if direction.path.is_ident("deserialize") {
+                        on_rename(name)?;
+                    }
+                    Ok(())
+                })?;
+            } else if meta.input.peek(Token![=]) {
+                meta.value()?.parse::<Expr>()?;
+            } else if meta.input.peek(token::Paren) {
+                meta.input.parse::<Group>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+/// The container's `#[serde(rename_all = "...")]` rule, if any
+fn rename_all(input: &DeriveInput) -> syn::Result<Option<RenameRule>> {
+    let mut rule = None;
+    serde_renames(&input.attrs, "rename_all", |name| {
+        rule = Some(RenameRule::from_name(&name)?);
+        Ok(())
+    })?;
+    Ok(rule)
+}
+
+/// The field's name in JSON
+///
+/// A field's own `#[serde(rename = "...")]` wins over the container's
+/// `rename_all` rule.
+fn json_name(field: &Field, rename_all: Option<RenameRule>) -> syn::Result<String> {
+    let ident = field.ident.as_ref().expect("named field").to_string();
+    let ident = ident.trim_start_matches("r#");
+    let mut name = match rename_all {
+        Some(rule) => rule.apply(ident),
+        None => ident.to_string(),
+    };
+    serde_renames(&field.attrs, "rename", |rename| {
+        name = rename.value();
+        Ok(())
+    })?;
+    Ok(name)
+}
+
+fn field_rules(field: &Field) -> syn::Result<Vec<Rule>> {
+    let mut rules = Vec::new();
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("validate")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("len_eq") {
+                let count: LitStr = meta.value()?.parse()?;
+                rules.push(Rule::LenEq(count.parse()?));
+            } else if meta.path.is_ident("non_empty") {
+                rules.push(Rule::NonEmpty);
+            } else if meta.path.is_ident("range") {
+                let (mut min, mut max) = (None, None);
+                meta.parse_nested_meta(|bound| {
+                    if bound.path.is_ident("min") {
+                        min = Some(Box::new(bound.value()?.parse()?));
+                    } else if bound.path.is_ident("max") {
+                        max = Some(Box::new(bound.value()?.parse()?));
+                    } else {
+                        return Err(bound.error("expected `min` or `max`"));
+                    }
+                    Ok(())
+                })?;
+                if min.is_none() && max.is_none() {
+                    return Err(meta.error("range requires `min`, `max` or both"));
+                }
+                rules.push(Rule::Range { min, max });
+            } else if meta.path.is_ident("custom") {
+                let function: LitStr = meta.value()?.parse()?;
+                rules.push(Rule::Custom(function.parse()?));
+            } else if meta.path.is_ident("nested") {
+                rules.push(Rule::Nested);
+            } else {
+                return Err(meta.error(
+                    "unsupported validate rule, expected `len_eq`, `non_empty`, `range`, `custom` or `nested`",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(rules)
+}
+
+impl Rule {
+    fn expand(&self, field: &Field, json_name: &str) -> TokenStream2 {
+        let ident = field.ident.as_ref().expect("named field");
+        let name = ident.to_string();
+        let name = name.trim_start_matches("r#");
+        let validation = quote!(::kodegen_mcp_client::validation);
+
+        match self {
+            Rule::LenEq(count) => {
+                let count_name = count.to_string();
+                quote! {
+                    if self.#ident.len() != self.#count as usize {
+                        errors.add(#json_name, #validation::count_mismatch_error(
+                            #count_name,
+                            self.#count as usize,
+                            self.#ident.len(),
+                        ));
+                    }
+                }
+            }
+            Rule::NonEmpty => quote! {
+                if self.#ident.is_empty() {
+                    errors.add(#json_name, #validation::empty_field_error(#name));
+                }
+            },
+            Rule::Range { min, max } => {
+                let min = min.iter().map(|min| {
+                    quote! {
+                        if self.#ident < #min {
+                            errors.add(
+                                #json_name,
+                                #validation::below_minimum_error(#name, &self.#ident, &#min),
+                            );
+                        }
+                    }
+                });
+                let max = max.iter().map(|max| {
+                    quote! {
+                        if self.#ident > #max {
+                            errors.add(
+                                #json_name,
+                                #validation::above_maximum_error(#name, &self.#ident, &#max),
+                            );
+                        }
+                    }
+                });
+                quote!(#(#min)* #(#max)*)
+            }
+            // Spanned so signature mismatches point at the attribute
+            Rule::Custom(function) => quote_spanned! {function.span()=>
+                if let ::core::result::Result::Err(message) = #function(&self.#ident) {
+                    errors.add(#json_name, message);
+                }
+            },
+            Rule::Nested => quote! {
+                errors.nested(#json_name, &self.#ident);
+            },
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! `kodegen-codegen`: generate Rust types from kodegen tool schemas
+//!
+//! ```text
+//! kodegen-codegen --url http://localhost:30443/mcp --out src/generated.rs
+//! kodegen-codegen --snapshot tools.json --out src/generated.rs
+//! kodegen-codegen --save-snapshot tools.json --stdio kodegen-filesystem --stdio-arg=--flag
+//! ```
+
+use anyhow::{Context, bail};
+use kodegen_mcp_client::{StdioClientBuilder, codegen, create_streamable_client};
+use reqwest::header::HeaderMap;
+use std::path::PathBuf;
+
+const USAGE: &str = "\
+Usage: kodegen-codegen <SOURCE> [--out FILE] [--save-snapshot FILE]
+
+Sources (exactly one):
+  --url URL            Streamable HTTP server endpoint
+  --stdio COMMAND      Server spawned over stdio; add arguments with --stdio-arg
+  --snapshot FILE      tools/list snapshot, for offline generation
+
+Options:
+  --stdio-arg ARG      Argument for the --stdio command (repeatable)
+  --out FILE           Write the generated Rust here instead of stdout
+  --save-snapshot FILE Also write the tools as a snapshot for later --snapshot runs
+";
+
+enum Source {
+    Url(String),
+    Stdio(String),
+    Snapshot(PathBuf),
+}
+
+struct Options {
+    source: Source,
+    stdio_args: Vec<String>,
+    out: Option<PathBuf>,
+    save_snapshot: Option<PathBuf>,
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> anyhow::Result<Options> {
+    let mut source = None;
+    let mut stdio_args = Vec::new();
+    let mut out = None;
+    let mut save_snapshot = None;
+
+    while let Some(flag) = args.next() {
+        let (flag, inline) = match flag.split_once('=') {
+            Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+            None => (flag, None),
+        };
+        if flag == "--help" || flag == "-h" {
+            print!("{USAGE}");
+            std::process::exit(0);
+        }
+        let Some(value) = inline.or_else(|| args.next()) else {
+            bail!("{flag} requires a value\n\n{USAGE}");
+        };
+        let previous = match flag.as_str() {
+            "--url" => source.replace(Source::Url(value)),
+            "--stdio" => source.replace(Source::Stdio(value)),
+            "--snapshot" => source.replace(Source::Snapshot(value.into())),
+            "--stdio-arg" => {
+                stdio_args.push(value);
+                None
+            }
+            "--out" => {
+                out = Some(value.into());
+                None
+            }
+            "--save-snapshot" => {
+                save_snapshot = Some(value.into());
+                None
+            }
+            _ => bail!("Unknown option {flag}\n\n{USAGE}"),
+        };
+        if previous.is_some() {
+            bail!("Only one of --url, --stdio and --snapshot may be given");
+        }
+    }
+
+    let Some(source) = source else {
+        bail!("No source given\n\n{USAGE}");
+    };
+    Ok(Options {
+        source,
+        stdio_args,
+        out,
+        save_snapshot,
+    })
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let options = parse_options(std::env::args().skip(1))?;
+
+    let tools = match options.source {
+        Source::Snapshot(path) => {
+            let json = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read snapshot {}", path.display()))?;
+            codegen::parse_snapshot(&json)
+                .with_context(|| format!("Invalid snapshot {}", path.display()))?
+        }
+        Source::Url(url) => {
+            let (client, conn) = create_streamable_client(&url, HeaderMap::new()).await?;
+            let tools = client.list_tools().await?;
+            conn.close().await?;
+            tools
+        }
+        Source::Stdio(command) => {
+            let (client, conn) = StdioClientBuilder::new(command)
+                .args(options.stdio_args)
+                .build()
+                .await?;
+            let tools = client.list_tools().await?;
+            conn.close().await?;
+            tools
+        }
+    };
+
+    if let Some(path) = options.save_snapshot {
+        std::fs::write(&path, codegen::to_snapshot(&tools)?)
+            .with_context(|| format!("Failed to write snapshot {}", path.display()))?;
+    }
+
+    let source = codegen::generate(&tools);
+    match options.out {
+        Some(path) => std::fs::write(&path, source)
+            .with_context(|| format!("Failed to write {}", path.display()))?,
+        None => print!("{source}"),
+    }
+    Ok(())
+}
@@ -0,0 +1,155 @@
+//! Cooperative cancellation of in-flight tool calls
+//!
+//! Dropping a request future only stops the client from waiting; the server keeps
+//! working. The helpers here send `notifications/cancelled` so the server can
+//! abort the request (e.g. stop a crawl or kill a terminal command).
+
+use crate::ClientError;
+use rmcp::{
+    RoleClient,
+    model::{CallToolResult, CancelledNotificationParam, RequestId},
+    service::Peer,
+};
+use std::{
+    future::{Future, IntoFuture},
+    pin::Pin,
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+/// Tell the server to stop working on `request_id`
+///
+/// Failures are only logged: the request is being abandoned either way.
+pub(crate) async fn notify_cancelled(
+    peer: &Peer<RoleClient>,
+    request_id: RequestId,
+    reason: Option<String>,
+) {
+    let param = CancelledNotificationParam { request_id, reason };
+    if let Err(e) = peer.notify_cancelled(param).await {
+        tracing::warn!(error = %e, "Failed to send notifications/cancelled");
+    }
+}
+
+/// Reason sent to the server when a request is abandoned after a timeout
+pub(crate) fn timeout_reason(duration: Duration) -> Option<String> {
+    Some(format!("Client timed out after {:?}", duration))
+}
+
+/// Handle to a tool call started by `KodegenClient::call_tool_cancellable()`
+///
+/// Await the handle (or call [`result`](Self::result)) to get the tool's result.
+/// The call runs on its own task, so cancelling the token or timing out notifies
+/// the server even if nothing is awaiting the handle. Dropping the handle does not
+/// cancel the call.
+///
+/// # Example
+///
+/// ```ignore
+/// use kodegen_mcp_client::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let call = client
+///     .call_tool_cancellable("start_crawl", json!({"url": url}), token.clone())
+///     .await?;
+///
+/// // Elsewhere, e.g. when the user presses Ctrl-C:
+/// call.cancel(Some("User aborted".to_string()));
+///
+/// match call.await {
+///     Err(ClientError::Cancelled { .. }) => println!("crawl stopped"),
+///     other => println!("{other:?}"),
+/// }
+/// ```
+#[must_use = "the call keeps running; await the handle to get its result"]
+pub struct ToolCallHandle {
+    request_id: RequestId,
+    cancellation: CancellationToken,
+    reason: Arc<OnceLock<String>>,
+    task: JoinHandle<Result<CallToolResult, ClientError>>,
+}
+
+impl std::fmt::Debug for ToolCallHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToolCallHandle")
+            .field("request_id", &self.request_id)
+            .field("cancelled", &self.cancellation.is_cancelled())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ToolCallHandle {
+    /// Spawn the task that waits for `response` while watching for cancellation
+    ///
+    /// `response` must resolve with the tool result, handling its own timeout.
+    pub(crate) fn spawn(
+        peer: Peer<RoleClient>,
+        request_id: RequestId,
+        operation: String,
+        cancellation: CancellationToken,
+        response: impl Future<Output = Result<CallToolResult, ClientError>> + Send + 'static,
+    ) -> Self {
+        let reason = Arc::new(OnceLock::new());
+        let task = tokio::spawn({
+            let request_id = request_id.clone();
+            let cancellation = cancellation.clone();
+            let reason = Arc::clone(&reason);
+            async move {
+                tokio::select! {
+                    result = response => result,
+                    () = cancellation.cancelled() => {
+                        let reason: Option<String> = reason.get().cloned();
+                        notify_cancelled(&peer, request_id, reason.clone()).await;
+                        Err(ClientError::Cancelled { operation, reason })
+                    }
+                }
+            }
+        });
+
+        Self {
+            request_id,
+            cancellation,
+            reason,
+            task,
+        }
+    }
+
+    /// JSON-RPC id of the request, as sent in `notifications/cancelled`
+    pub fn request_id(&self) -> &RequestId {
+        &self.request_id
+    }
+
+    /// Cancel the call, sending `reason` to the server
+    ///
+    /// Equivalent to cancelling the token passed to `call_tool_cancellable()`,
+    /// but lets a reason be attached. Has no effect once the call has completed.
+    pub fn cancel(&self, reason: Option<String>) {
+        if let Some(reason) = reason {
+            // Only the first reason is kept if cancel is called repeatedly
+            let _ = self.reason.set(reason);
+        }
+        self.cancellation.cancel();
+    }
+
+    /// Wait for the call to finish
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Cancelled` if the call was cancelled,
+    /// `ClientError::Timeout` if it exceeded the client's timeout (the server is
+    /// notified in both cases), or `ClientError::ServiceError` if the call fails.
+    pub async fn result(self) -> Result<CallToolResult, ClientError> {
+        self.task.await?
+    }
+}
+
+impl IntoFuture for ToolCallHandle {
+    type Output = Result<CallToolResult, ClientError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<CallToolResult, ClientError>> + Send>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.result())
+    }
+}
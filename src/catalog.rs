@@ -0,0 +1,168 @@
+//! Per-connection cache of the server's tool list
+//!
+//! [`ToolCatalog`] holds the result of the last complete `tools/list`, indexed
+//! by name. Every client handle of a connection shares it; it is filled by
+//! `list_tools()` (or lazily on first lookup) and invalidated when the server
+//! sends `notifications/tools/list_changed`.
+
+use crate::{ClientError, schema};
+use jsonschema::Validator;
+use rmcp::model::Tool;
+use std::{
+    collections::HashMap,
+    sync::{Arc, OnceLock, RwLock},
+};
+
+/// Cached tool list shared by every client handle of a connection
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ToolCatalog {
+    inner: Arc<RwLock<CatalogState>>,
+}
+
+#[derive(Debug, Default)]
+struct CatalogState {
+    /// Bumped on every invalidation so listings started earlier are discarded
+    generation: u64,
+    tools: Option<Arc<ToolSnapshot>>,
+}
+
+impl ToolCatalog {
+    /// The cached tool list, or `None` if it must be fetched
+    pub(crate) fn snapshot(&self) -> Option<Arc<ToolSnapshot>> {
+        self.read().tools.clone()
+    }
+
+    /// Generation to pass to [`store`](Self::store) for a listing started now
+    pub(crate) fn generation(&self) -> u64 {
+        self.read().generation
+    }
+
+    /// Cache a complete tool listing started at `generation`
+    ///
+    /// The listing is only cached if the catalog was not invalidated while it
+    /// was in flight; the snapshot is returned either way.
+    pub(crate) fn store(&self, generation: u64, tools: Vec<Tool>) -> Arc<ToolSnapshot> {
+        let snapshot = Arc::new(ToolSnapshot::new(tools));
+        let mut state = self.write();
+        if state.generation == generation {
+            state.tools = Some(Arc::clone(&snapshot));
+        }
+        snapshot
+    }
+
+    /// Forget the cached tool list
+    pub(crate) fn invalidate(&self) {
+        let mut state = self.write();
+        state.generation += 1;
+        state.tools = None;
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, CatalogState> {
+        self.inner
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, CatalogState> {
+        self.inner
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// One complete tool listing, indexed by name
+#[derive(Debug)]
+pub(crate) struct ToolSnapshot {
+    /// Tools in the order the server listed them
+    tools: Vec<CatalogEntry>,
+    index: HashMap<String, usize>,
+}
+
+/// A tool and its lazily compiled input schema validator
+struct CatalogEntry {
+    tool: Tool,
+    validator: OnceLock<Option<Validator>>,
+}
+
+impl std::fmt::Debug for CatalogEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CatalogEntry")
+            .field("tool", &self.tool.name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ToolSnapshot {
+    fn new(tools: Vec<Tool>) -> Self {
+        let index = tools
+            .iter()
+            .enumerate()
+            .map(|(idx, tool)| (tool.name.to_string(), idx))
+            .collect();
+        let tools = tools
+            .into_iter()
+            .map(|tool| CatalogEntry {
+                tool,
+                validator: OnceLock::new(),
+            })
+            .collect();
+        Self { tools, index }
+    }
+
+    /// Look up a tool by name
+    pub(crate) fn tool(&self, name: &str) -> Option<&Tool> {
+        self.entry(name).map(|entry| &entry.tool)
+    }
+
+    /// Tool names in the order the server listed them
+    pub(crate) fn names(&self) -> Vec<String> {
+        self.tools
+            .iter()
+            .map(|entry| entry.tool.name.to_string())
+            .collect()
+    }
+
+    /// Validate `arguments` against the input schema of `tool_name`
+    ///
+    /// Passes when the tool is unknown or its schema does not compile; the
+    /// server remains the authority on those calls.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::InvalidArguments` listing every violation.
+    pub(crate) fn validate_arguments(
+        &self,
+        tool_name: &str,
+        arguments: &serde_json::Value,
+    ) -> Result<(), ClientError> {
+        let Some(entry) = self.entry(tool_name) else {
+            return Ok(());
+        };
+
+        let validator = entry.validator.get_or_init(|| {
+            let schema = serde_json::Value::Object((*entry.tool.input_schema).clone());
+            jsonschema::validator_for(&schema)
+                .inspect_err(|e| {
+                    tracing::warn!(tool = tool_name, error = %e, "Skipping argument validation: invalid inputSchema");
+                })
+                .ok()
+        });
+        let Some(validator) = validator else {
+            return Ok(());
+        };
+
+        let violations = schema::violations(validator, arguments);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ClientError::InvalidArguments {
+                tool_name: tool_name.to_string(),
+                violations,
+            })
+        }
+    }
+
+    fn entry(&self, name: &str) -> Option<&CatalogEntry> {
+        self.index.get(name).map(|&idx| &self.tools[idx])
+    }
+}
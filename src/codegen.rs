@@ -0,0 +1,520 @@
+//! Rust type generation from tool schemas
+//!
+//! Turns the `inputSchema` and `outputSchema` of each tool into argument and
+//! response structs with serde attributes, plus a [`KodegenTool`](crate::KodegenTool)
+//! marker tying them to the tool's name. Tools come either from a live server
+//! ([`generate_from_client`]) or from a JSON snapshot of a `tools/list` result
+//! ([`parse_snapshot`]), so generation also works offline in CI. The
+//! `kodegen-codegen` binary wraps both.
+//!
+//! ```ignore
+//! use kodegen_mcp_client::codegen;
+//!
+//! let tools = codegen::parse_snapshot(&std::fs::read_to_string("tools.json")?)?;
+//! std::fs::write("src/generated.rs", codegen::generate(&tools))?;
+//! ```
+//!
+//! Supported schema features: object properties (required fields become plain
+//! types, others `Option`), string enums, arrays, nested objects, nullable
+//! types (`"type": [T, "null"]` and `anyOf` with `null`) and local `$ref`s to
+//! `$defs`/`definitions`. Anything else maps to `serde_json::Value`.
+
+use crate::{ClientError, KodegenClient};
+use rmcp::model::{ListToolsResult, Tool};
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+
+/// Header emitted at the top of every generated file
+const HEADER: &str = "\
+// Generated by kodegen-codegen from tool schemas. Do not edit by hand.
+
+#[allow(unused_imports)]
+use kodegen_mcp_client::{KodegenTool, validation::Validate};
+use serde::{Deserialize, Serialize};
+";
+
+/// Generate Rust source for `tools`
+///
+/// The output is a self-contained module body: one marker type, argument
+/// struct and (when the tool declares an `outputSchema` describing an object)
+/// response struct per tool, followed by any nested types they need. Tools
+/// without a usable output schema get `serde_json::Value` as their output.
+#[must_use]
+pub fn generate(tools: &[Tool]) -> String {
+    let mut generator = Generator::default();
+    for tool in tools {
+        generator.tool(tool);
+    }
+
+    let mut source = HEADER.to_string();
+    for item in generator.items {
+        source.push('\n');
+        source.push_str(&item);
+    }
+    source
+}
+
+/// List the server's tools and generate Rust source for them
+///
+/// # Errors
+///
+/// Returns any error from `list_tools()`.
+pub async fn generate_from_client(client: &KodegenClient) -> Result<String, ClientError> {
+    let tools = client.list_tools().await?;
+    Ok(generate(&tools))
+}
+
+/// Parse a tool snapshot
+///
+/// Accepts either a `tools/list` result (`{"tools": [...]}`) or a bare array
+/// of tools.
+///
+/// # Errors
+///
+/// Returns an error if `json` is neither shape.
+pub fn parse_snapshot(json: &str) -> Result<Vec<Tool>, serde_json::Error> {
+    match serde_json::from_str::<Value>(json)? {
+        Value::Array(tools) => serde_json::from_value(Value::Array(tools)),
+        other => Ok(serde_json::from_value::<ListToolsResult>(other)?.tools),
+    }
+}
+
+/// Render `tools` as a snapshot for [`parse_snapshot`]
+///
+/// # Errors
+///
+/// Returns an error if a tool fails to serialize.
+pub fn to_snapshot(tools: &[Tool]) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(&serde_json::json!({ "tools": tools }))
+}
+
+/// Whether a struct is sent to or received from the server
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Args,
+    Output,
+}
+
+#[derive(Default)]
+struct Generator {
+    /// Emitted items, each a complete Rust item with its doc comments
+    items: Vec<String>,
+    /// Type names already taken
+    names: HashSet<String>,
+    /// Type name of each emitted `$ref` definition, keyed by its JSON
+    definitions: HashMap<String, String>,
+    /// Non-struct `$ref` definitions being resolved, to break cycles
+    resolving: HashSet<String>,
+    /// Structs whose fields are being emitted, innermost last
+    building: Vec<String>,
+}
+
+impl Generator {
+    fn tool(&mut self, tool: &Tool) {
+        let marker = self.unique_name(&pascal_case(&tool.name));
+        // Reserve the slot so the marker precedes the types it names
+        let slot = self.items.len();
+        self.items.push(String::new());
+
+        let root = Value::Object((*tool.input_schema).clone());
+        let args = self.object_struct(&format!("{marker}Args"), &root, &root, Role::Args);
+
+        let output = match tool.output_schema.as_deref() {
+            Some(schema) if schema.contains_key("properties") => {
+                let root = Value::Object(schema.clone());
+                self.object_struct(&format!("{marker}Output"), &root, &root, Role::Output)
+            }
+            _ => "serde_json::Value".to_string(),
+        };
+
+        let mut item = doc_comment(tool.description.as_deref().unwrap_or_default(), "");
+        item.push_str(&format!(
+            "#[derive(Debug, Clone, Copy)]\n\
+             pub struct {marker};\n\
+             \n\
+             impl KodegenTool for {marker} {{\n    \
+                 const NAME: &'static str = {name:?};\n    \
+                 type Args = {args};\n    \
+                 type Output = {output};\n\
+             }}\n",
+            name = tool.name.as_ref(),
+        ));
+        self.items[slot] = item;
+    }
+
+    /// Rust type for `schema`, emitting any structs or enums it needs
+    ///
+    /// `hint` names a type emitted for this schema; `root` is the schema that
+    /// local `$ref`s resolve against.
+    fn type_for(&mut self, hint: &str, schema: &Value, root: &Value, role: Role) -> String {
+        let Some(object) = schema.as_object() else {
+            return "serde_json::Value".to_string();
+        };
+
+        if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+            return self.reference(reference, root, role);
+        }
+        if let Some(inner) = nullable_any_of(object) {
+            return format!("Option<{}>", self.type_for(hint, inner, root, role));
+        }
+        if let Some([inner]) = object
+            .get("allOf")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+        {
+            return self.type_for(hint, inner, root, role);
+        }
+        if let Some(variants) = object.get("enum").and_then(Value::as_array) {
+            return self.string_enum(hint, variants, object);
+        }
+
+        match object.get("type") {
+            Some(Value::String(ty)) => self.typed(ty, hint, schema, root, role),
+            Some(Value::Array(types)) => {
+                let non_null: Vec<_> = types.iter().filter(|t| *t != "null").collect();
+                match non_null.as_slice() {
+                    [Value::String(ty)] if non_null.len() < types.len() => {
+                        format!("Option<{}>", self.typed(ty, hint, schema, root, role))
+                    }
+                    [Value::String(ty)] => self.typed(ty, hint, schema, root, role),
+                    _ => "serde_json::Value".to_string(),
+                }
+            }
+            _ if object.contains_key("properties") => self.object_struct(hint, schema, root, role),
+            _ => "serde_json::Value".to_string(),
+        }
+    }
+
+    fn typed(&mut self, ty: &str, hint: &str, schema: &Value, root: &Value, role: Role) -> String {
+        match ty {
+            "string" => "String".to_string(),
+            "boolean" => "bool".to_string(),
+            "number" => "f64".to_string(),
+            "integer" => {
+                let unsigned = schema
+                    .get("minimum")
+                    .and_then(Value::as_f64)
+                    .is_some_and(|min| min >= 0.0);
+                if unsigned { "u64" } else { "i64" }.to_string()
+            }
+            "array" => match schema.get("items") {
+                Some(items) => {
+                    format!(
+                        "Vec<{}>",
+                        self.type_for(&format!("{hint}Item"), items, root, role)
+                    )
+                }
+                None => "Vec<serde_json::Value>".to_string(),
+            },
+            "object" if schema.get("properties").is_some() => {
+                self.object_struct(hint, schema, root, role)
+            }
+            "object" => match schema.get("additionalProperties") {
+                Some(values @ Value::Object(_)) => format!(
+                    "std::collections::HashMap<String, {}>",
+                    self.type_for(&format!("{hint}Value"), values, root, role)
+                ),
+                _ => "serde_json::Map<String, serde_json::Value>".to_string(),
+            },
+            _ => "serde_json::Value".to_string(),
+        }
+    }
+
+    /// Type name for a local `$ref`, emitting the definition on first use
+    fn reference(&mut self, reference: &str, root: &Value, role: Role) -> String {
+        let Some(definition) = reference
+            .strip_prefix('#')
+            .and_then(|pointer| root.pointer(pointer))
+        else {
+            return "serde_json::Value".to_string();
+        };
+
+        let key = format!("{reference}:{definition}");
+        if let Some(name) = self.definitions.get(&key) {
+            return name.clone();
+        }
+        let hint = pascal_case(reference.rsplit('/').next().unwrap_or_default());
+
+        // Name structs before emitting their fields so self-references resolve
+        if is_struct_schema(definition) {
+            let name = self.unique_name(&hint);
+            self.definitions.insert(key, name.clone());
+            return self.emit_struct(name, definition, root, role);
+        }
+        // A definition that only ever expands to itself has no Rust type
+        if !self.resolving.insert(key.clone()) {
+            return "serde_json::Value".to_string();
+        }
+        let name = self.type_for(&hint, definition, root, role);
+        self.resolving.remove(&key);
+        self.definitions.insert(key, name.clone());
+        name
+    }
+
+    /// Emit a struct for an object schema and return its name
+    fn object_struct(&mut self, hint: &str, schema: &Value, root: &Value, role: Role) -> String {
+        let name = self.unique_name(hint);
+        self.emit_struct(name, schema, root, role)
+    }
+
+    /// Emit the struct `name` for an object schema and return the name
+    fn emit_struct(&mut self, name: String, schema: &Value, root: &Value, role: Role) -> String {
+        // Reserve the slot so the struct precedes the nested types it uses
+        let slot = self.items.len();
+        self.items.push(String::new());
+        self.building.push(name.clone());
+
+        let required: HashSet<&str> = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|names| names.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        let properties = schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .cloned()
+            .unwrap_or_default();
+
+        let mut fields = String::new();
+        let mut field_names = HashSet::new();
+        for (json_name, property) in &properties {
+            let field = unique_field(&mut field_names, field_name(json_name));
+            let ty = self.type_for(
+                &format!("{name}{}", pascal_case(json_name)),
+                property,
+                root,
+                role,
+            );
+            let mut ty = self.boxed(ty);
+            let mut attrs = Vec::new();
+            if field.trim_start_matches("r#") != json_name {
+                attrs.push(format!("rename = {json_name:?}"));
+            }
+            if !required.contains(json_name.as_str()) {
+                if !ty.starts_with("Option<") {
+                    ty = format!("Option<{ty}>");
+                }
+                attrs.push("default".to_string());
+                attrs.push("skip_serializing_if = \"Option::is_none\"".to_string());
+            }
+
+            fields.push_str(&doc_comment(description(property), "    "));
+            if !attrs.is_empty() {
+                fields.push_str(&format!("    #[serde({})]\n", attrs.join(", ")));
+            }
+            fields.push_str(&format!("    pub {field}: {ty},\n"));
+        }
+
+        self.building.pop();
+        let derives = match role {
+            Role::Args => "Debug, Clone, PartialEq, Serialize, Deserialize",
+            Role::Output => "Debug, Clone, PartialEq, Serialize, Deserialize, Validate",
+        };
+        let mut item = doc_comment(description(schema), "");
+        if fields.is_empty() {
+            item.push_str(&format!("#[derive({derives})]\npub struct {name} {{}}\n"));
+        } else {
+            item.push_str(&format!(
+                "#[derive({derives})]\npub struct {name} {{\n{fields}}}\n"
+            ));
+        }
+        self.items[slot] = item;
+        name
+    }
+
+    /// Emit an enum for a string `enum` schema and return its name
+    ///
+    /// Falls back to `String` (or `Value` for non-string variants) when the
+    /// values cannot be turned into distinct variant names.
+    fn string_enum(&mut self, hint: &str, values: &[Value], schema: &Map<String, Value>) -> String {
+        let Some(values): Option<Vec<&str>> = values.iter().map(Value::as_str).collect() else {
+            return "serde_json::Value".to_string();
+        };
+        let variants: Vec<String> = values.iter().map(|value| pascal_case(value)).collect();
+        let distinct: HashSet<&String> = variants.iter().collect();
+        if values.is_empty()
+            || distinct.len() != variants.len()
+            || variants.iter().any(|v| !is_identifier(v))
+        {
+            return "String".to_string();
+        }
+
+        let name = self.unique_name(hint);
+        let mut item = doc_comment(
+            schema
+                .get("description")
+                .and_then(Value::as_str)
+                .unwrap_or_default(),
+            "",
+        );
+        item.push_str(&format!(
+            "#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]\npub enum {name} {{\n"
+        ));
+        for (value, variant) in values.iter().zip(&variants) {
+            item.push_str(&format!(
+                "    #[serde(rename = {value:?})]\n    {variant},\n"
+            ));
+        }
+        item.push_str("}\n");
+        self.items.push(item);
+        name
+    }
+
+    /// Box direct references to structs still being emitted
+    ///
+    /// A struct containing itself (directly or through an `Option`) would have
+    /// infinite size; `Vec` and map fields are already indirect.
+    fn boxed(&self, ty: String) -> String {
+        let building = |name: &str| self.building.iter().any(|b| b == name);
+        match ty.strip_prefix("Option<").and_then(|t| t.strip_suffix('>')) {
+            Some(inner) if building(inner) => format!("Option<Box<{inner}>>"),
+            None if building(&ty) => format!("Box<{ty}>"),
+            _ => ty,
+        }
+    }
+
+    fn unique_name(&mut self, base: &str) -> String {
+        let base = if is_identifier(base) {
+            base.to_string()
+        } else {
+            format!("Generated{base}")
+        };
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while !self.names.insert(name.clone()) {
+            name = format!("{base}{suffix}");
+            suffix += 1;
+        }
+        name
+    }
+}
+
+/// Whether `type_for` emits a struct for `schema` itself
+fn is_struct_schema(schema: &Value) -> bool {
+    let Some(object) = schema.as_object() else {
+        return false;
+    };
+    let wrapped = ["$ref", "anyOf", "oneOf", "allOf", "enum"]
+        .iter()
+        .any(|key| object.contains_key(*key));
+    let is_object = match object.get("type") {
+        Some(ty) => ty == "object",
+        None => true,
+    };
+    !wrapped && is_object && object.contains_key("properties")
+}
+
+/// The non-null branch of `anyOf: [T, {"type": "null"}]`
+fn nullable_any_of(schema: &Map<String, Value>) -> Option<&Value> {
+    let branches = schema
+        .get("anyOf")
+        .or_else(|| schema.get("oneOf"))?
+        .as_array()?;
+    let is_null = |branch: &Value| branch.get("type").and_then(Value::as_str) == Some("null");
+    match branches.as_slice() {
+        [a, b] if is_null(b) => Some(a),
+        [a, b] if is_null(a) => Some(b),
+        _ => None,
+    }
+}
+
+fn description(schema: &Value) -> &str {
+    schema
+        .get("description")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+}
+
+fn doc_comment(text: &str, indent: &str) -> String {
+    text.trim()
+        .lines()
+        .map(|line| {
+            let line = line.trim_end();
+            if line.is_empty() {
+                format!("{indent}///\n")
+            } else {
+                format!("{indent}/// {line}\n")
+            }
+        })
+        .collect()
+}
+
+/// `snake_case` field name for a JSON property, escaping keywords
+fn field_name(json_name: &str) -> String {
+    let mut name = String::new();
+    let mut previous: Option<char> = None;
+    for c in json_name.chars() {
+        if c.is_ascii_alphanumeric() {
+            let boundary = previous.is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit());
+            if c.is_ascii_uppercase() && boundary {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+        } else if !name.is_empty() && !name.ends_with('_') {
+            name.push('_');
+        }
+        previous = Some(c);
+    }
+    let name = name.trim_end_matches('_');
+
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("field_{name}")
+    } else if matches!(name, "self" | "super" | "crate" | "Self") {
+        format!("{name}_")
+    } else if KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+/// `field`, or `field` with a numeric suffix if another property already maps to it
+///
+/// Distinct JSON names such as `fooBar` and `foo_bar` share a `snake_case`
+/// form; the renamed field keeps its JSON name through `#[serde(rename)]`.
+fn unique_field(taken: &mut HashSet<String>, field: String) -> String {
+    let base = field.trim_start_matches("r#").to_string();
+    if taken.insert(base.clone()) {
+        return field;
+    }
+    let mut suffix = 2;
+    loop {
+        let candidate = format!("{base}_{suffix}");
+        if taken.insert(candidate.clone()) {
+            return candidate;
+        }
+        suffix += 1;
+    }
+}
+
+/// `PascalCase` type or variant name for a tool name, property or enum value
+fn pascal_case(value: &str) -> String {
+    let mut name = String::new();
+    let mut upper = true;
+    for c in value.chars() {
+        if c.is_ascii_alphanumeric() {
+            if upper {
+                name.push(c.to_ascii_uppercase());
+            } else {
+                name.push(c);
+            }
+            upper = false;
+        } else {
+            upper = true;
+        }
+    }
+    name
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic()) && name != "Self"
+}
+
+/// Keywords usable as raw identifiers
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
@@ -0,0 +1,265 @@
+//! Following long-running `scrape_url` crawls
+//!
+//! `scrape_url` returns a crawl ID as soon as the crawl has started; crawls
+//! then run for minutes. [`CrawlSession`] polls the crawl's status, hands out
+//! each crawled page once, searches what has been fetched, and cancels the
+//! crawl on the server if it is dropped before the crawl finishes.
+
+use crate::{
+    ClientError, KodegenClient,
+    polling::{Paged, spawn_cleanup, stream_items},
+    requests::{
+        CancelCrawl, CancelCrawlArgs, CrawlStatus, CrawlStatusArgs, SearchCrawl, SearchCrawlArgs,
+    },
+    responses::{
+        StartCrawlResponse,
+        citescrape::{
+            CancelCrawlResponse, CrawlSearchResponse, CrawlState, CrawlStatusResponse, CrawledPage,
+        },
+    },
+};
+use std::time::Duration;
+use tokio_stream::Stream;
+
+/// Delay between status polls that report no new pages
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Handle to a crawl started with `scrape_url`
+///
+/// Created by [`KodegenClient::start_crawl`]. Status polls continue where the
+/// previous one stopped, so [`status`](Self::status) and
+/// [`pages`](Self::pages) never return a page twice.
+///
+/// Dropping the session before the crawl finishes cancels it on the server
+/// from a background task on the current Tokio runtime. That is best effort:
+/// nothing is sent when the session is dropped outside a runtime, and the task
+/// may never run if the runtime is shutting down. Call [`close`](Self::close)
+/// to cancel and wait for the server to confirm.
+///
+/// # Example
+///
+/// ```ignore
+/// use kodegen_mcp_client::requests::StartCrawlArgs;
+/// use tokio_stream::StreamExt;
+///
+/// let mut crawl = client
+///     .start_crawl(StartCrawlArgs::new("https://docs.rs/tokio"))
+///     .await?;
+///
+/// let mut pages = std::pin::pin!(crawl.pages());
+/// while let Some(page) = pages.next().await {
+///     let page = page?;
+///     println!("[{}] depth {} {}", page.status, page.depth, page.url);
+/// }
+/// drop(pages);
+///
+/// let hits = crawl.search("spawn_blocking").await?;
+/// for hit in hits.hits {
+///     println!("{}: {}", hit.url, hit.snippet);
+/// }
+/// ```
+pub struct CrawlSession {
+    client: KodegenClient,
+    crawl_id: String,
+    offset: u64,
+    state: CrawlState,
+    error: Option<String>,
+    finished: bool,
+    poll_interval: Duration,
+}
+
+impl CrawlSession {
+    /// Attach to a crawl started with `scrape_url`
+    ///
+    /// Pages are read from the first one.
+    pub fn new(client: KodegenClient, started: &StartCrawlResponse) -> Self {
+        Self {
+            client,
+            crawl_id: started.crawl_id.clone(),
+            offset: 0,
+            state: CrawlState::Running,
+            error: None,
+            finished: false,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// Set the delay between status polls that report no new pages (default 1s)
+    #[must_use]
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// ID of the crawl on the server
+    #[must_use]
+    pub fn crawl_id(&self) -> &str {
+        &self.crawl_id
+    }
+
+    /// Number of pages read so far
+    #[must_use]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// State of the crawl as of the last poll
+    #[must_use]
+    pub fn state(&self) -> CrawlState {
+        self.state
+    }
+
+    /// Why the crawl failed, if the last poll reported a failure
+    #[must_use]
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Whether the crawl has stopped and every crawled page has been read
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Poll the crawl's state and the pages crawled since the previous poll
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `KodegenClient::invoke()`.
+    pub async fn status(&mut self) -> Result<CrawlStatusResponse, ClientError> {
+        let response = self
+            .client
+            .invoke::<CrawlStatus>(CrawlStatusArgs::new(&self.crawl_id, self.offset))
+            .await?;
+        self.offset += response.pages.len() as u64;
+        self.state = response.state;
+        self.error.clone_from(&response.error);
+        // Crawls may count pages they never return (failed or deduplicated
+        // fetches), so a stopped crawl with nothing new is done too
+        self.finished = response.state.is_finished()
+            && (self.offset >= response.pages_crawled || response.pages.is_empty());
+        Ok(response)
+    }
+
+    /// Stream every remaining page as the crawl fetches it
+    ///
+    /// Ends once the crawl has stopped and all of its pages have been read;
+    /// check [`state`](Self::state) afterwards to tell completion from failure
+    /// or cancellation. The first error ends the stream after being yielded.
+    pub fn pages(&mut self) -> impl Stream<Item = Result<CrawledPage, ClientError>> + '_ {
+        stream_items(self)
+    }
+
+    /// Wait for the crawl to stop and return its final state
+    ///
+    /// Pages reported while waiting are discarded; use [`pages`](Self::pages)
+    /// to keep them. Wrap the call in `tokio::time::timeout` to bound the wait.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `status()`.
+    pub async fn wait(&mut self) -> Result<CrawlState, ClientError> {
+        while !self.finished {
+            let response = self.status().await?;
+            if response.pages.is_empty() && !self.finished {
+                tokio::time::sleep(self.poll_interval).await;
+            }
+        }
+        Ok(self.state)
+    }
+
+    /// Search the content crawled so far
+    ///
+    /// Works while the crawl is running and after it has finished.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `KodegenClient::invoke()`.
+    pub async fn search(
+        &self,
+        query: impl Into<String>,
+    ) -> Result<CrawlSearchResponse, ClientError> {
+        self.client
+            .invoke::<SearchCrawl>(SearchCrawlArgs::new(&self.crawl_id, query))
+            .await
+    }
+
+    /// Cancel the crawl on the server
+    ///
+    /// The session is finished afterwards: [`pages`](Self::pages) ends and
+    /// [`wait`](Self::wait) returns right away. Pages crawled before the
+    /// cancellation can still be searched.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `KodegenClient::invoke()`.
+    pub async fn cancel(&mut self) -> Result<CancelCrawlResponse, ClientError> {
+        let response = self
+            .client
+            .invoke::<CancelCrawl>(CancelCrawlArgs::new(&self.crawl_id))
+            .await?;
+        if self.state == CrawlState::Running {
+            self.state = CrawlState::Cancelled;
+        }
+        self.finished = true;
+        Ok(response)
+    }
+
+    /// Cancel the crawl if it is still running, waiting for the server's reply
+    ///
+    /// Unlike dropping the session, this does not depend on a background task.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `cancel()`; the cancellation is not retried when
+    /// the session is dropped.
+    pub async fn close(mut self) -> Result<(), ClientError> {
+        if self.state.is_finished() {
+            return Ok(());
+        }
+        let result = self.cancel().await.map(|_| ());
+        self.state = CrawlState::Cancelled;
+        result
+    }
+}
+
+impl std::fmt::Debug for CrawlSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CrawlSession")
+            .field("crawl_id", &self.crawl_id)
+            .field("offset", &self.offset)
+            .field("state", &self.state)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Paged for CrawlSession {
+    type Item = CrawledPage;
+
+    async fn next_items(&mut self) -> Result<Option<Vec<CrawledPage>>, ClientError> {
+        while !self.finished {
+            let response = self.status().await?;
+            if !response.pages.is_empty() {
+                return Ok(Some(response.pages));
+            }
+            if !self.finished {
+                tokio::time::sleep(self.poll_interval).await;
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl Drop for CrawlSession {
+    fn drop(&mut self) {
+        if self.state.is_finished() {
+            return;
+        }
+        let client = self.client.clone();
+        let args = CancelCrawlArgs::new(std::mem::take(&mut self.crawl_id));
+        spawn_cleanup(format!("crawl {}", args.crawl_id), async move {
+            client.invoke::<CancelCrawl>(args).await
+        });
+    }
+}
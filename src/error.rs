@@ -1,4 +1,5 @@
-use rmcp::service::ClientInitializeError;
+use crate::validation::ValidationErrors;
+use rmcp::{model::Content, service::ClientInitializeError};
 use std::time::Duration;
 use thiserror::Error;
 
@@ -34,12 +35,49 @@ pub enum ClientError {
         duration: Duration,
     },
 
+    #[error("Operation '{operation}' was cancelled")]
+    Cancelled {
+        operation: String,
+        reason: Option<String>,
+    },
+
+    #[error("Tool '{tool_name}' reported an error: {message}")]
+    ToolError {
+        tool_name: String,
+        message: String,
+        content: Vec<Content>,
+    },
+
     #[error("Failed to parse response from tool '{tool_name}': {source}")]
     ParseError {
         tool_name: String,
         source: serde_json::Error,
     },
 
+    #[error(
+        "Output of tool '{tool_name}' does not match its outputSchema: {}",
+        format_violations(violations)
+    )]
+    OutputSchemaMismatch {
+        tool_name: String,
+        violations: Vec<SchemaViolation>,
+    },
+
+    #[error("Response from tool '{tool_name}' failed validation: {errors}")]
+    ValidationError {
+        tool_name: String,
+        errors: ValidationErrors,
+    },
+
+    #[error(
+        "Arguments for tool '{tool_name}' do not match its inputSchema: {}",
+        format_violations(violations)
+    )]
+    InvalidArguments {
+        tool_name: String,
+        violations: Vec<SchemaViolation>,
+    },
+
     #[error("Connection error: {message}")]
     Connection {
         message: String,
@@ -48,6 +86,34 @@ pub enum ClientError {
     },
 }
 
+/// A single JSON Schema violation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation {
+    /// JSON pointer to the offending value (empty for the document root)
+    pub instance_path: String,
+    /// Human-readable description of the violation
+    pub message: String,
+}
+
+impl std::fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = if self.instance_path.is_empty() {
+            "/"
+        } else {
+            &self.instance_path
+        };
+        write!(f, "{}: {}", path, self.message)
+    }
+}
+
+fn format_violations(violations: &[SchemaViolation]) -> String {
+    violations
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 // Manual From implementation to handle boxing of ClientInitializeError
 impl From<ClientInitializeError> for ClientError {
     fn from(error: ClientInitializeError) -> Self {
@@ -104,9 +170,14 @@ impl ClientError {
                 _ => "service error",
             },
             ClientError::Timeout { .. } => "timeout",
+            ClientError::Cancelled { .. } => "cancelled",
             ClientError::Connection { .. } => "connection error",
             ClientError::Protocol(_) => "protocol error",
+            ClientError::ToolError { .. } => "tool error",
             ClientError::ParseError { .. } => "parse error",
+            ClientError::OutputSchemaMismatch { .. } => "output schema mismatch",
+            ClientError::ValidationError { .. } => "validation error",
+            ClientError::InvalidArguments { .. } => "invalid arguments",
             ClientError::Io(_) => "io error",
             ClientError::JoinError(_) => "task join error",
         }
@@ -0,0 +1,550 @@
+//! Client-side handler for server-initiated MCP requests
+//!
+//! `KodegenClientHandler` is the rmcp service that every kodegen connection runs.
+//! It advertises client capabilities based on which callbacks are registered and
+//! routes incoming server requests (e.g. `sampling/createMessage`, `roots/list`,
+//! `elicitation/create`) to them. Server notifications are broadcast to every
+//! `NotificationStream` subscriber.
+
+use crate::{
+    catalog::ToolCatalog,
+    notifications::{NOTIFICATION_CHANNEL_CAPACITY, ServerNotification},
+    transports::create_client_info,
+};
+use rmcp::{
+    ClientHandler, ErrorData, RoleClient,
+    model::{
+        CancelledNotificationParam, ClientCapabilities, ClientInfo, CreateElicitationRequestParam,
+        CreateElicitationResult, CreateMessageRequestMethod, CreateMessageRequestParam,
+        CreateMessageResult, ElicitationAction, ElicitationCapability,
+        ElicitationCreateRequestMethod, JsonObject, ListRootsRequestMethod, ListRootsResult,
+        LoggingMessageNotificationParam, ProgressNotificationParam,
+        ResourceUpdatedNotificationParam, Root, RootsCapabilities,
+    },
+    service::{NotificationContext, RequestContext},
+};
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    path::Path,
+    pin::Pin,
+    sync::{Arc, RwLock},
+};
+use tokio::sync::broadcast;
+
+/// Boxed future returned by handler callbacks
+pub type HandlerFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Handler for `sampling/createMessage` requests from the server
+///
+/// Registering a sampling handler advertises the `sampling` capability during
+/// initialization, allowing the server to ask the host's LLM for completions.
+///
+/// Any `Fn(CreateMessageRequestParam) -> impl Future` closure implements this trait:
+///
+/// ```ignore
+/// use rmcp::model::{CreateMessageResult, Content, Role, SamplingMessage};
+///
+/// let (client, _conn) = StdioClientBuilder::new("kodegen")
+///     .sampling_handler(|params: CreateMessageRequestParam| async move {
+///         let reply = my_llm.complete(&params.messages).await;
+///         Ok(CreateMessageResult {
+///             model: "my-model".to_string(),
+///             stop_reason: Some(CreateMessageResult::STOP_REASON_END_TURN.to_string()),
+///             message: SamplingMessage {
+///                 role: Role::Assistant,
+///                 content: Content::text(reply),
+///             },
+///         })
+///     })
+///     .build()
+///     .await?;
+/// ```
+pub trait SamplingHandler: Send + Sync + 'static {
+    /// Generate a message for the server's sampling request
+    ///
+    /// # Errors
+    /// Returns an MCP error that is sent back to the server as the request result
+    fn create_message(
+        &self,
+        params: CreateMessageRequestParam,
+    ) -> HandlerFuture<'_, Result<CreateMessageResult, ErrorData>>;
+}
+
+impl<F, Fut> SamplingHandler for F
+where
+    F: Fn(CreateMessageRequestParam) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<CreateMessageResult, ErrorData>> + Send + 'static,
+{
+    fn create_message(
+        &self,
+        params: CreateMessageRequestParam,
+    ) -> HandlerFuture<'_, Result<CreateMessageResult, ErrorData>> {
+        Box::pin(self(params))
+    }
+}
+
+/// Source of the workspace roots reported to the server via `roots/list`
+///
+/// Implemented for a static `Vec<Root>` and for any `Fn() -> impl Future` closure,
+/// so roots can either be fixed at build time or computed on every request:
+///
+/// ```ignore
+/// // Static roots
+/// let builder = StdioClientBuilder::new("kodegen")
+///     .roots(vec![path_root("/project", Some("project"))]);
+///
+/// // Computed on each roots/list request
+/// let workspace = shared_workspace.clone();
+/// let builder = StdioClientBuilder::new("kodegen")
+///     .roots(move || {
+///         let workspace = workspace.clone();
+///         async move { Ok(vec![path_root(workspace.lock().await.as_path(), None)]) }
+///     });
+/// ```
+pub trait RootsProvider: Send + Sync + 'static {
+    /// Return the current workspace roots
+    ///
+    /// # Errors
+    /// Returns an MCP error that is sent back to the server as the request result
+    fn list_roots(&self) -> HandlerFuture<'_, Result<Vec<Root>, ErrorData>>;
+}
+
+impl RootsProvider for Vec<Root> {
+    fn list_roots(&self) -> HandlerFuture<'_, Result<Vec<Root>, ErrorData>> {
+        Box::pin(std::future::ready(Ok(self.clone())))
+    }
+}
+
+impl<F, Fut> RootsProvider for F
+where
+    F: Fn() -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<Vec<Root>, ErrorData>> + Send + 'static,
+{
+    fn list_roots(&self) -> HandlerFuture<'_, Result<Vec<Root>, ErrorData>> {
+        Box::pin(self())
+    }
+}
+
+/// Build a `file://` root for a local directory
+///
+/// Relative paths are resolved against the current working directory. Paths that
+/// cannot be expressed as a file URI fall back to a lossy `file://` string.
+///
+/// # Example
+///
+/// ```ignore
+/// let root = path_root("/project", Some("project"));
+/// assert_eq!(root.uri, "file:///project/");
+/// ```
+pub fn path_root(path: impl AsRef<Path>, name: Option<&str>) -> Root {
+    let path = path.as_ref();
+    let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    let uri = url::Url::from_directory_path(&absolute)
+        .map(String::from)
+        .unwrap_or_else(|_| format!("file://{}", absolute.to_string_lossy()));
+
+    Root {
+        uri,
+        name: name.map(str::to_string),
+    }
+}
+
+/// User's answer to an `elicitation/create` request
+#[derive(Debug, Clone, PartialEq)]
+pub enum ElicitationResponse {
+    /// User provided the requested data, which should match the requested schema
+    Accept(serde_json::Value),
+    /// User declined to provide the data; the server may continue without it
+    Decline,
+    /// User dismissed the request; the server should abort the operation
+    Cancel,
+}
+
+impl From<ElicitationResponse> for CreateElicitationResult {
+    fn from(response: ElicitationResponse) -> Self {
+        match response {
+            ElicitationResponse::Accept(content) => Self {
+                action: ElicitationAction::Accept,
+                content: Some(content),
+            },
+            ElicitationResponse::Decline => Self {
+                action: ElicitationAction::Decline,
+                content: None,
+            },
+            ElicitationResponse::Cancel => Self {
+                action: ElicitationAction::Cancel,
+                content: None,
+            },
+        }
+    }
+}
+
+/// Handler for `elicitation/create` requests from the server
+///
+/// Servers use elicitation to ask the user for structured input mid-operation.
+/// The request carries a human-readable `message` and a `requested_schema`
+/// describing the expected object. Registering a handler advertises the
+/// `elicitation` capability.
+///
+/// Any `Fn(CreateElicitationRequestParam) -> impl Future` closure implements this trait:
+///
+/// ```ignore
+/// let builder = StdioClientBuilder::new("kodegen")
+///     .elicitation_handler(|request: CreateElicitationRequestParam| async move {
+///         match ui.ask(&request.message, &request.requested_schema).await {
+///             Some(answer) => Ok(ElicitationResponse::Accept(answer)),
+///             None => Ok(ElicitationResponse::Decline),
+///         }
+///     });
+/// ```
+///
+/// For CI, [`FixtureElicitationHandler`] answers from a fixed map instead of a human.
+pub trait ElicitationHandler: Send + Sync + 'static {
+    /// Answer the server's elicitation request
+    ///
+    /// # Errors
+    /// Returns an MCP error that is sent back to the server as the request result
+    fn elicit(
+        &self,
+        request: CreateElicitationRequestParam,
+    ) -> HandlerFuture<'_, Result<ElicitationResponse, ErrorData>>;
+}
+
+impl<F, Fut> ElicitationHandler for F
+where
+    F: Fn(CreateElicitationRequestParam) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<ElicitationResponse, ErrorData>> + Send + 'static,
+{
+    fn elicit(
+        &self,
+        request: CreateElicitationRequestParam,
+    ) -> HandlerFuture<'_, Result<ElicitationResponse, ErrorData>> {
+        Box::pin(self(request))
+    }
+}
+
+/// Elicitation handler that answers from a fixture map keyed by message
+///
+/// Intended for tests and CI, where flows that would otherwise block on a human
+/// must run unattended. Requests whose message has no fixture get the fallback
+/// response (`Decline` unless changed with `otherwise()`).
+///
+/// # Example
+///
+/// ```ignore
+/// let fixtures = FixtureElicitationHandler::new()
+///     .answer("Which branch should be deployed?", json!({"branch": "main"}))
+///     .answer("Confirm deletion", json!({"confirm": true}))
+///     .otherwise(ElicitationResponse::Cancel);
+///
+/// let (client, _conn) = StdioClientBuilder::new("kodegen")
+///     .elicitation_handler(fixtures)
+///     .build()
+///     .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixtureElicitationHandler {
+    answers: HashMap<String, serde_json::Value>,
+    fallback: ElicitationResponse,
+}
+
+impl Default for FixtureElicitationHandler {
+    fn default() -> Self {
+        Self {
+            answers: HashMap::new(),
+            fallback: ElicitationResponse::Decline,
+        }
+    }
+}
+
+impl FixtureElicitationHandler {
+    /// Create a handler with no fixtures that declines every request
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accept requests with exactly this message, answering with `content`
+    #[must_use]
+    pub fn answer(mut self, message: impl Into<String>, content: serde_json::Value) -> Self {
+        self.answers.insert(message.into(), content);
+        self
+    }
+
+    /// Response for requests whose message has no fixture
+    #[must_use]
+    pub fn otherwise(mut self, response: ElicitationResponse) -> Self {
+        self.fallback = response;
+        self
+    }
+
+    /// Resolve the response for a message without going through the protocol
+    pub fn response_for(&self, message: &str) -> ElicitationResponse {
+        self.answers
+            .get(message)
+            .map(|content| ElicitationResponse::Accept(content.clone()))
+            .unwrap_or_else(|| self.fallback.clone())
+    }
+}
+
+impl ElicitationHandler for FixtureElicitationHandler {
+    fn elicit(
+        &self,
+        request: CreateElicitationRequestParam,
+    ) -> HandlerFuture<'_, Result<ElicitationResponse, ErrorData>> {
+        Box::pin(std::future::ready(Ok(self.response_for(&request.message))))
+    }
+}
+
+/// Callbacks registered on a builder before the connection exists
+///
+/// Cloning only copies `Arc` pointers, so builders stay cheap to clone.
+#[derive(Clone, Default)]
+pub(crate) struct ClientHandlers {
+    pub(crate) sampling: Option<Arc<dyn SamplingHandler>>,
+    pub(crate) roots: Option<Arc<dyn RootsProvider>>,
+    pub(crate) elicitation: Option<Arc<dyn ElicitationHandler>>,
+}
+
+impl fmt::Debug for ClientHandlers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientHandlers")
+            .field("sampling", &self.sampling.is_some())
+            .field("roots", &self.roots.is_some())
+            .field("elicitation", &self.elicitation.is_some())
+            .finish()
+    }
+}
+
+/// rmcp service handler used by all kodegen connections
+///
+/// Most users never construct this directly; the transport builders create it.
+/// Use it with `KodegenConnection::from_service()` when serving a custom transport:
+///
+/// ```ignore
+/// use rmcp::ServiceExt;
+///
+/// let service = KodegenClientHandler::new("my-client")
+///     .with_sampling_handler(my_sampler)
+///     .serve(transport)
+///     .await?;
+/// let connection = KodegenConnection::from_service(service);
+/// ```
+#[derive(Clone)]
+pub struct KodegenClientHandler {
+    info: ClientInfo,
+    sampling: Option<Arc<dyn SamplingHandler>>,
+    elicitation: Option<Arc<dyn ElicitationHandler>>,
+    /// Swapped by `KodegenConnection::set_roots()`; `None` means the roots
+    /// capability is not advertised
+    roots: Arc<RwLock<Option<Arc<dyn RootsProvider>>>>,
+    /// Fan-out of server notifications to every `NotificationStream`
+    notifications: broadcast::Sender<ServerNotification>,
+    /// Cached tool list, invalidated on `notifications/tools/list_changed`
+    tool_catalog: ToolCatalog,
+}
+
+impl fmt::Debug for KodegenClientHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KodegenClientHandler")
+            .field("info", &self.info)
+            .field("sampling", &self.sampling.is_some())
+            .field("roots", &self.has_roots())
+            .field("elicitation", &self.elicitation.is_some())
+            .finish()
+    }
+}
+
+impl KodegenClientHandler {
+    /// Create a handler with no callbacks registered
+    ///
+    /// # Arguments
+    /// * `name` - Client identifier sent during initialization
+    pub fn new(name: impl Into<String>) -> Self {
+        Self::from_parts(create_client_info(name), ClientHandlers::default())
+    }
+
+    /// Create a handler from client info and builder-registered callbacks
+    pub(crate) fn from_parts(info: ClientInfo, handlers: ClientHandlers) -> Self {
+        Self {
+            info,
+            sampling: handlers.sampling,
+            elicitation: handlers.elicitation,
+            roots: Arc::new(RwLock::new(handlers.roots)),
+            notifications: broadcast::channel(NOTIFICATION_CHANNEL_CAPACITY).0,
+            tool_catalog: ToolCatalog::default(),
+        }
+    }
+
+    /// Register a handler for `sampling/createMessage` requests
+    #[must_use]
+    pub fn with_sampling_handler(mut self, handler: impl SamplingHandler) -> Self {
+        self.sampling = Some(Arc::new(handler));
+        self
+    }
+
+    /// Register a handler for `elicitation/create` requests
+    #[must_use]
+    pub fn with_elicitation_handler(mut self, handler: impl ElicitationHandler) -> Self {
+        self.elicitation = Some(Arc::new(handler));
+        self
+    }
+
+    /// Register a provider answering `roots/list` requests
+    ///
+    /// Advertises the `roots` capability with `listChanged` support.
+    #[must_use]
+    pub fn with_roots(mut self, provider: impl RootsProvider) -> Self {
+        let provider: Arc<dyn RootsProvider> = Arc::new(provider);
+        self.roots = Arc::new(RwLock::new(Some(provider)));
+        self
+    }
+
+    /// Whether a roots provider is registered
+    pub(crate) fn has_roots(&self) -> bool {
+        self.roots
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .is_some()
+    }
+
+    /// Swap the roots provider used for subsequent `roots/list` requests
+    pub(crate) fn replace_roots(&self, provider: Arc<dyn RootsProvider>) {
+        *self
+            .roots
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(provider);
+    }
+
+    /// Sender side of the notification broadcast, shared with client handles
+    pub(crate) fn notification_sender(&self) -> broadcast::Sender<ServerNotification> {
+        self.notifications.clone()
+    }
+
+    /// Tool catalog shared by the connection's client handles
+    pub(crate) fn tool_catalog(&self) -> ToolCatalog {
+        self.tool_catalog.clone()
+    }
+
+    /// Broadcast a notification; dropped silently when nobody is subscribed
+    fn publish(&self, notification: ServerNotification) {
+        let _ = self.notifications.send(notification);
+    }
+
+    /// Client capabilities derived from the registered callbacks
+    fn capabilities(&self) -> ClientCapabilities {
+        ClientCapabilities {
+            sampling: self.sampling.as_ref().map(|_| JsonObject::new()),
+            roots: self.has_roots().then_some(RootsCapabilities {
+                list_changed: Some(true),
+            }),
+            elicitation: self.elicitation.as_ref().map(|_| ElicitationCapability {
+                schema_validation: None,
+            }),
+            ..self.info.capabilities.clone()
+        }
+    }
+}
+
+impl ClientHandler for KodegenClientHandler {
+    async fn create_message(
+        &self,
+        params: CreateMessageRequestParam,
+        _context: RequestContext<RoleClient>,
+    ) -> Result<CreateMessageResult, ErrorData> {
+        match &self.sampling {
+            Some(handler) => handler.create_message(params).await,
+            None => Err(ErrorData::method_not_found::<CreateMessageRequestMethod>()),
+        }
+    }
+
+    async fn list_roots(
+        &self,
+        _context: RequestContext<RoleClient>,
+    ) -> Result<ListRootsResult, ErrorData> {
+        // Clone the provider out so the lock is not held across the await
+        let provider = self
+            .roots
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+
+        match provider {
+            Some(provider) => Ok(ListRootsResult {
+                roots: provider.list_roots().await?,
+            }),
+            None => Err(ErrorData::method_not_found::<ListRootsRequestMethod>()),
+        }
+    }
+
+    async fn create_elicitation(
+        &self,
+        request: CreateElicitationRequestParam,
+        _context: RequestContext<RoleClient>,
+    ) -> Result<CreateElicitationResult, ErrorData> {
+        match &self.elicitation {
+            Some(handler) => handler.elicit(request).await.map(Into::into),
+            None => Err(ErrorData::method_not_found::<ElicitationCreateRequestMethod>()),
+        }
+    }
+
+    async fn on_cancelled(
+        &self,
+        params: CancelledNotificationParam,
+        _context: NotificationContext<RoleClient>,
+    ) {
+        self.publish(ServerNotification::Cancelled {
+            request_id: params.request_id,
+            reason: params.reason,
+        });
+    }
+
+    async fn on_progress(
+        &self,
+        params: ProgressNotificationParam,
+        _context: NotificationContext<RoleClient>,
+    ) {
+        self.publish(params.into());
+    }
+
+    async fn on_logging_message(
+        &self,
+        params: LoggingMessageNotificationParam,
+        _context: NotificationContext<RoleClient>,
+    ) {
+        self.publish(ServerNotification::LoggingMessage {
+            level: params.level,
+            logger: params.logger,
+            data: params.data,
+        });
+    }
+
+    async fn on_resource_updated(
+        &self,
+        params: ResourceUpdatedNotificationParam,
+        _context: NotificationContext<RoleClient>,
+    ) {
+        self.publish(ServerNotification::ResourceUpdated { uri: params.uri });
+    }
+
+    async fn on_resource_list_changed(&self, _context: NotificationContext<RoleClient>) {
+        self.publish(ServerNotification::ResourceListChanged);
+    }
+
+    async fn on_tool_list_changed(&self, _context: NotificationContext<RoleClient>) {
+        self.tool_catalog.invalidate();
+        self.publish(ServerNotification::ToolListChanged);
+    }
+
+    async fn on_prompt_list_changed(&self, _context: NotificationContext<RoleClient>) {
+        self.publish(ServerNotification::PromptListChanged);
+    }
+
+    fn get_info(&self) -> ClientInfo {
+        ClientInfo {
+            capabilities: self.capabilities(),
+            ..self.info.clone()
+        }
+    }
+}
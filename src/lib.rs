@@ -1,19 +1,63 @@
+// Lets `#[derive(Validate)]` refer to `::kodegen_mcp_client` inside this crate
+extern crate self as kodegen_mcp_client;
+
+use cancellation::{notify_cancelled, timeout_reason};
+use catalog::{ToolCatalog, ToolSnapshot};
+use options::{CallHeaders, next_progress_token};
 use rmcp::{
-    RoleClient,
-    model::{CallToolRequestParam, CallToolResult, ClientInfo, InitializeResult},
-    service::{Peer, RunningService},
+    RoleClient, ServiceError,
+    model::{
+        CallToolRequest, CallToolRequestParam, CallToolResult, ClientRequest, GetExtensions,
+        GetPromptRequestParam, GetPromptResult, InitializeResult, JsonObject, ListToolsRequest,
+        Meta, PaginatedRequestParam, ProgressToken, Prompt, ReadResourceRequestParam,
+        ReadResourceResult, Resource, ResourceTemplate, Root, ServerResult, SubscribeRequestParam,
+        UnsubscribeRequestParam,
+    },
+    service::{Peer, PeerRequestOptions, RequestHandle, RunningService},
+};
+use std::{future::Future, sync::Arc};
+use tokio::{
+    sync::{broadcast, mpsc, oneshot},
+    time::{Duration, Instant, sleep, timeout},
 };
-use tokio::time::{Duration, timeout};
 
+pub mod cancellation;
+mod catalog;
+pub mod codegen;
+pub mod crawl;
 pub mod error;
+pub mod handler;
 pub mod headers;
+pub mod notifications;
+pub mod options;
+mod polling;
+pub mod requests;
 pub mod responses;
+mod schema;
+pub mod search;
+pub mod terminal;
+pub mod tools;
 pub mod transports;
 pub mod validation;
 
-pub use error::{ClientError, TransportType};
+pub use cancellation::ToolCallHandle;
+pub use crawl::CrawlSession;
+pub use error::{ClientError, SchemaViolation, TransportType};
+pub use handler::{
+    ElicitationHandler, ElicitationResponse, FixtureElicitationHandler, KodegenClientHandler,
+    RootsProvider, SamplingHandler, path_root,
+};
 pub use headers::{X_KODEGEN_CONNECTION_ID, X_KODEGEN_GITROOT, X_KODEGEN_PWD};
-pub use transports::{StdioClientBuilder, create_stdio_client, create_streamable_client};
+pub use notifications::{NotificationStream, ServerNotification, ToolProgress};
+pub use options::{CallOptions, RetryPolicy};
+pub use requests::KodegenTool;
+pub use search::SearchSession;
+pub use terminal::TerminalSession;
+pub use tokio_util::sync::CancellationToken;
+pub use transports::{
+    StdioClientBuilder, StreamableClientBuilder, create_stdio_client, create_streamable_client,
+};
+pub use validation::Validate;
 
 /// Get human-readable JSON type name for error messages
 fn json_type_name(value: &serde_json::Value) -> &'static str {
@@ -27,15 +71,231 @@ fn json_type_name(value: &serde_json::Value) -> &'static str {
     }
 }
 
+/// Render typed prompt arguments into the string map MCP prompts expect
+///
+/// Strings pass through unchanged; other scalars use their JSON text (`42`,
+/// `true`), and nested arrays/objects are rendered as compact JSON.
+fn render_prompt_arguments(
+    name: &str,
+    arguments: serde_json::Value,
+) -> Result<Option<JsonObject>, ClientError> {
+    match arguments {
+        serde_json::Value::Object(map) => Ok(Some(
+            map.into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| {
+                    let rendered = match value {
+                        serde_json::Value::String(s) => s,
+                        other => other.to_string(),
+                    };
+                    (key, serde_json::Value::String(rendered))
+                })
+                .collect(),
+        )),
+        serde_json::Value::Null => Ok(None),
+        other => Err(ClientError::Protocol(format!(
+            "Prompt '{}' arguments must be a JSON object or null, got {}",
+            name,
+            json_type_name(&other)
+        ))),
+    }
+}
+
+/// Deserialize a tool result, preferring `structured_content` over text content
+///
+/// When `output_schema` is given, the JSON is validated against it first.
+fn parse_tool_result<T>(
+    name: &str,
+    result: CallToolResult,
+    output_schema: Option<&JsonObject>,
+) -> Result<T, ClientError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let result = check_tool_result(name, result)?;
+    let parse_error = |source| ClientError::ParseError {
+        tool_name: name.to_string(),
+        source,
+    };
+
+    if let Some(structured) = result.structured_content {
+        if let Some(schema) = output_schema {
+            schema::validate_output(name, schema, &structured)?;
+        }
+        return serde_json::from_value(structured).map_err(parse_error);
+    }
+
+    // Fall back to the first text content item
+    let text_content = result
+        .content
+        .iter()
+        .find_map(|c| c.as_text())
+        .ok_or_else(|| {
+            if result.content.is_empty() {
+                ClientError::Protocol(format!("Tool '{}' returned empty content array", name))
+            } else {
+                // Show what content types were returned
+                let content_types: Vec<_> = result
+                    .content
+                    .iter()
+                    .map(|c| match c.raw {
+                        rmcp::model::RawContent::Text(_) => "text",
+                        rmcp::model::RawContent::Image(_) => "image",
+                        rmcp::model::RawContent::Resource(_) => "resource",
+                        rmcp::model::RawContent::Audio(_) => "audio",
+                        rmcp::model::RawContent::ResourceLink(_) => "resource_link",
+                    })
+                    .collect();
+
+                ClientError::Protocol(format!(
+                    "Tool '{}' returned {} content item(s) but none were text: [{}]",
+                    name,
+                    result.content.len(),
+                    content_types.join(", ")
+                ))
+            }
+        })?;
+
+    // Deserialize to target type with context
+    match output_schema {
+        Some(schema) => {
+            let value = serde_json::from_str(&text_content.text).map_err(parse_error)?;
+            schema::validate_output(name, schema, &value)?;
+            serde_json::from_value(value).map_err(parse_error)
+        }
+        None => serde_json::from_str(&text_content.text).map_err(parse_error),
+    }
+}
+
+/// Serialize typed tool arguments to the JSON object sent with the call
+fn tool_arguments<A: serde::Serialize>(
+    name: &str,
+    args: &A,
+) -> Result<serde_json::Value, ClientError> {
+    let arguments = serde_json::to_value(args).map_err(|e| {
+        ClientError::Protocol(format!(
+            "Failed to serialize arguments for tool '{}': {}",
+            name, e
+        ))
+    })?;
+    if !arguments.is_object() {
+        return Err(ClientError::Protocol(format!(
+            "Arguments for tool '{}' must serialize to a JSON object, got {}",
+            name,
+            json_type_name(&arguments)
+        )));
+    }
+    Ok(arguments)
+}
+
+/// Check a deserialized response's invariants
+fn validate_response<T: Validate>(name: &str, response: T) -> Result<T, ClientError> {
+    response
+        .validate()
+        .map_err(|errors| ClientError::ValidationError {
+            tool_name: name.to_string(),
+            errors,
+        })?;
+    Ok(response)
+}
+
+/// Build tool call parameters, accepting only object or null arguments
+fn tool_call_params(
+    name: &str,
+    arguments: serde_json::Value,
+) -> Result<CallToolRequestParam, ClientError> {
+    Ok(CallToolRequestParam {
+        // name.to_string() allocation is required because CallToolRequestParam
+        // expects Cow<'static, str>. Cannot use borrowed reference from &str parameter
+        // as it doesn't satisfy the 'static lifetime requirement.
+        name: name.to_string().into(),
+        arguments: match arguments {
+            serde_json::Value::Object(map) => Some(map),
+            serde_json::Value::Null => None,
+            other => {
+                return Err(ClientError::Protocol(format!(
+                    "Tool arguments must be a JSON object or null, got {}",
+                    json_type_name(&other)
+                )));
+            }
+        },
+    })
+}
+
+/// Extract the `tools/call` result from a raw response
+fn tool_call_result(
+    response: Result<Result<ServerResult, ServiceError>, oneshot::error::RecvError>,
+) -> Result<CallToolResult, ClientError> {
+    match response.map_err(|_| ServiceError::TransportClosed)?? {
+        ServerResult::CallToolResult(result) => Ok(result),
+        _ => Err(ServiceError::UnexpectedResponse.into()),
+    }
+}
+
+/// Turn a result the server flagged with `isError` into `ClientError::ToolError`
+///
+/// The error message is the result's text content joined by newlines.
+fn check_tool_result(name: &str, result: CallToolResult) -> Result<CallToolResult, ClientError> {
+    if result.is_error != Some(true) {
+        return Ok(result);
+    }
+
+    let text: Vec<_> = result
+        .content
+        .iter()
+        .filter_map(|c| c.as_text())
+        .map(|t| t.text.as_str())
+        .collect();
+    let message = if text.is_empty() {
+        "no error message provided".to_string()
+    } else {
+        text.join("\n")
+    };
+
+    Err(ClientError::ToolError {
+        tool_name: name.to_string(),
+        message,
+        content: result.content,
+    })
+}
+
+/// Wait for the next progress update for the tracked request
+///
+/// Never resolves when progress is not being tracked.
+async fn next_progress(
+    progress: &mut Option<(
+        broadcast::Receiver<ServerNotification>,
+        &mpsc::UnboundedSender<ToolProgress>,
+        &ProgressToken,
+    )>,
+) -> ToolProgress {
+    let Some((notifications, _, token)) = progress else {
+        return std::future::pending().await;
+    };
+    loop {
+        // Lagged receivers skip ahead; the sender lives as long as the client
+        if let Ok(notification) = notifications.recv().await
+            && let Some(update) = notification.progress_for(token)
+        {
+            return update;
+        }
+    }
+}
+
 /// Default timeout for MCP operations (10 minutes)
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(600);
 
+/// Upper bound on the `tools/list` a tool call runs to validate its arguments
+///
+/// Keeps a slow or hung listing from holding up the call itself.
+const CATALOG_FETCH_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// Cheap-to-clone client handle for MCP operations
 ///
 /// This handle can be cloned freely and shared across tasks/threads. The handle
 /// wraps a `Peer<RoleClient>` from the `rmcp` crate, which internally contains
-/// four Arc pointers. Cloning only copies these Arc pointers (16 bytes each on
-/// 64-bit systems) and increments reference counts atomically.
+/// four Arc pointers, plus the notification broadcast sender (one more Arc).
+/// Cloning only copies these Arc pointers and increments reference counts atomically.
 ///
 /// ## Thread Safety
 ///
@@ -65,8 +325,8 @@ const DEFAULT_TIMEOUT: Duration = Duration::from_secs(600);
 ///
 /// ## Performance
 ///
-/// - Clone cost: ~48 bytes + 4 atomic increments (near-zero overhead)
-/// - Memory per client: ~32 bytes (Arc pointers + Duration)
+/// - Clone cost: ~64 bytes + 6 atomic increments (near-zero overhead)
+/// - Memory per client: ~48 bytes (Arc pointers + Duration + flags)
 /// - No limit on number of clones (uses standard Arc reference counting)
 /// - All clones share the same underlying MCP connection
 ///
@@ -85,15 +345,21 @@ const DEFAULT_TIMEOUT: Duration = Duration::from_secs(600);
 #[derive(Clone)]
 pub struct KodegenClient {
     peer: Peer<RoleClient>,
+    notifications: broadcast::Sender<ServerNotification>,
+    tool_catalog: ToolCatalog,
     default_timeout: Duration,
+    validate_arguments: bool,
 }
 
 impl KodegenClient {
-    /// Create a client from a peer (internal use)
-    pub(crate) fn from_peer(peer: Peer<RoleClient>) -> Self {
+    /// Create a client from a peer and the handler serving its connection (internal use)
+    pub(crate) fn from_peer(peer: Peer<RoleClient>, handler: &KodegenClientHandler) -> Self {
         Self {
             peer,
+            notifications: handler.notification_sender(),
+            tool_catalog: handler.tool_catalog(),
             default_timeout: DEFAULT_TIMEOUT,
+            validate_arguments: true,
         }
     }
 
@@ -102,6 +368,31 @@ impl KodegenClient {
         &self.peer
     }
 
+    /// Subscribe to notifications sent by the server
+    ///
+    /// Every call returns an independent stream, so cloned handles can each
+    /// listen without stealing notifications from one another. Only
+    /// notifications received after this call are delivered.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use tokio_stream::StreamExt;
+    ///
+    /// let mut notifications = client.subscribe();
+    /// tokio::spawn(async move {
+    ///     while let Some(notification) = notifications.next().await {
+    ///         if let ServerNotification::LoggingMessage { level, data, .. } = notification {
+    ///             println!("[{level:?}] {data}");
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    #[must_use]
+    pub fn subscribe(&self) -> NotificationStream {
+        NotificationStream::new(self.notifications.subscribe())
+    }
+
     /// Configure custom timeout for all operations
     ///
     /// This creates a client handle with a different timeout configuration.
@@ -131,63 +422,744 @@ impl KodegenClient {
         self
     }
 
+    /// Enable or disable client-side validation of tool arguments
+    ///
+    /// Enabled by default: tool calls check their arguments against the tool's
+    /// `inputSchema` before sending and fail with `ClientError::InvalidArguments`
+    /// instead of making a round trip the server would reject. Schemas come from
+    /// the connection's last `tools/list` (fetched on the first call if needed)
+    /// and are refreshed after `notifications/tools/list_changed`. That fetch
+    /// is capped at 5 seconds; if it fails, the call is sent unvalidated and a
+    /// warning is logged.
+    ///
+    /// Disable it for servers whose schemas are stricter than what they accept.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let lenient = conn.client().with_argument_validation(false);
+    /// lenient.call_tool("legacy_tool", args).await?;
+    /// ```
+    #[must_use]
+    pub fn with_argument_validation(mut self, enabled: bool) -> Self {
+        self.validate_arguments = enabled;
+        self
+    }
+
     /// Get server information
     #[must_use]
     pub fn server_info(&self) -> Option<&InitializeResult> {
         self.peer.peer_info()
     }
 
+    /// Run an MCP request under this handle's timeout
+    ///
+    /// The operation name is only built when the timeout fires.
+    async fn run_with_timeout<T, F>(
+        &self,
+        operation: impl FnOnce() -> String,
+        request: F,
+    ) -> Result<T, ClientError>
+    where
+        F: Future<Output = Result<T, rmcp::ServiceError>>,
+    {
+        timeout(self.default_timeout, request)
+            .await
+            .map_err(|_| ClientError::Timeout {
+                operation: operation(),
+                duration: self.default_timeout,
+            })?
+            .map_err(ClientError::from)
+    }
+
     /// List all available tools
     ///
+    /// Always fetches every page from the server and refreshes the connection's
+    /// tool catalog. For repeated lookups use [`tool`](Self::tool),
+    /// [`has_tool`](Self::has_tool) or [`tool_names`](Self::tool_names), which
+    /// are served from the catalog.
+    ///
     /// # Errors
     ///
     /// Returns `ClientError::Timeout` if the operation exceeds the configured timeout,
     /// or `ClientError::ServiceError` if the MCP request fails.
     pub async fn list_tools(&self) -> Result<Vec<rmcp::model::Tool>, ClientError> {
-        timeout(self.default_timeout, self.peer.list_all_tools())
+        let generation = self.tool_catalog.generation();
+        let tools = self
+            .run_with_timeout(|| "list_tools".to_string(), self.peer.list_all_tools())
+            .await?;
+        self.tool_catalog.store(generation, tools.clone());
+        Ok(tools)
+    }
+
+    /// List all available tools with per-call options
+    ///
+    /// Follows pagination cursors like `list_tools()`. The timeout covers the
+    /// whole listing, while retries and headers apply to each page request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Timeout` if the listing exceeds the timeout or deadline,
+    /// `ClientError::Cancelled` if the options' cancellation token fires,
+    /// or `ClientError::ServiceError` if the MCP request fails.
+    pub async fn list_tools_with(
+        &self,
+        options: &CallOptions,
+    ) -> Result<Vec<rmcp::model::Tool>, ClientError> {
+        let generation = self.tool_catalog.generation();
+        let tools = self.fetch_tools(options).await?;
+        self.tool_catalog.store(generation, tools.clone());
+        Ok(tools)
+    }
+
+    /// Look up a tool by name in the connection's tool catalog
+    ///
+    /// The catalog holds the last complete `tools/list` and is shared by every
+    /// handle of the connection. It is fetched on first use and again after the
+    /// server sends `notifications/tools/list_changed`; otherwise lookups make
+    /// no round trip.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if let Some(tool) = client.tool("fs_read_file").await? {
+    ///     println!("{}", serde_json::to_string_pretty(&tool.input_schema)?);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of `list_tools()` when the catalog has to be fetched.
+    pub async fn tool(&self, name: &str) -> Result<Option<rmcp::model::Tool>, ClientError> {
+        Ok(self
+            .tool_snapshot(&CallOptions::default())
+            .await?
+            .tool(name)
+            .cloned())
+    }
+
+    /// Check whether the server offers a tool, using the tool catalog
+    ///
+    /// See [`tool`](Self::tool) for caching behavior.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of `list_tools()` when the catalog has to be fetched.
+    pub async fn has_tool(&self, name: &str) -> Result<bool, ClientError> {
+        Ok(self
+            .tool_snapshot(&CallOptions::default())
+            .await?
+            .tool(name)
+            .is_some())
+    }
+
+    /// Names of all tools in the catalog, in the order the server listed them
+    ///
+    /// See [`tool`](Self::tool) for caching behavior.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of `list_tools()` when the catalog has to be fetched.
+    pub async fn tool_names(&self) -> Result<Vec<String>, ClientError> {
+        Ok(self.tool_snapshot(&CallOptions::default()).await?.names())
+    }
+
+    /// The cached tool catalog, fetching it with `options` if it is cold
+    async fn tool_snapshot(&self, options: &CallOptions) -> Result<Arc<ToolSnapshot>, ClientError> {
+        if let Some(snapshot) = self.tool_catalog.snapshot() {
+            return Ok(snapshot);
+        }
+        let generation = self.tool_catalog.generation();
+        let tools = self.fetch_tools(options).await?;
+        Ok(self.tool_catalog.store(generation, tools))
+    }
+
+    /// Fetch every page of `tools/list`; the timeout covers the whole listing
+    async fn fetch_tools(
+        &self,
+        options: &CallOptions,
+    ) -> Result<Vec<rmcp::model::Tool>, ClientError> {
+        let timeout = options.timeout.unwrap_or(self.default_timeout);
+        let options = options.clone().deadline(options.expiry(timeout));
+
+        let mut tools = Vec::new();
+        let mut cursor = None;
+        loop {
+            let request = ClientRequest::ListToolsRequest(ListToolsRequest::with_param(
+                PaginatedRequestParam { cursor },
+            ));
+            match self
+                .request_with_options(&|| "list_tools".to_string(), request, &options)
+                .await?
+            {
+                ServerResult::ListToolsResult(page) => {
+                    tools.extend(page.tools);
+                    cursor = page.next_cursor;
+                }
+                _ => return Err(ServiceError::UnexpectedResponse.into()),
+            }
+            if cursor.is_none() {
+                return Ok(tools);
+            }
+        }
+    }
+
+    /// List all available resources
+    ///
+    /// Follows `next_cursor` until the server reports no more pages, so the
+    /// returned list is complete. The timeout covers the whole pagination.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Timeout` if the operation exceeds the configured timeout,
+    /// or `ClientError::ServiceError` if the MCP request fails.
+    pub async fn list_resources(&self) -> Result<Vec<Resource>, ClientError> {
+        self.run_with_timeout(
+            || "list_resources".to_string(),
+            self.peer.list_all_resources(),
+        )
+        .await
+    }
+
+    /// List all available resource templates
+    ///
+    /// Like `list_resources()`, all pages are fetched before returning.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Timeout` if the operation exceeds the configured timeout,
+    /// or `ClientError::ServiceError` if the MCP request fails.
+    pub async fn list_resource_templates(&self) -> Result<Vec<ResourceTemplate>, ClientError> {
+        self.run_with_timeout(
+            || "list_resource_templates".to_string(),
+            self.peer.list_all_resource_templates(),
+        )
+        .await
+    }
+
+    /// Read the contents of a resource by URI
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let result = client.read_resource("file:///project/schema.sql").await?;
+    /// for contents in result.contents {
+    ///     if let ResourceContents::TextResourceContents { text, .. } = contents {
+    ///         println!("{text}");
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Timeout` if the operation exceeds the configured timeout,
+    /// or `ClientError::ServiceError` if the resource does not exist or the request fails.
+    pub async fn read_resource(&self, uri: &str) -> Result<ReadResourceResult, ClientError> {
+        let request = self.peer.read_resource(ReadResourceRequestParam {
+            uri: uri.to_string(),
+        });
+
+        self.run_with_timeout(|| format!("read_resource '{}'", uri), request)
             .await
-            .map_err(|_| ClientError::Timeout {
-                operation: "list_tools".to_string(),
-                duration: self.default_timeout,
-            })?
-            .map_err(ClientError::from)
     }
 
-    /// Call a tool by name with JSON arguments
+    /// Subscribe to update notifications for a resource
+    ///
+    /// The server must advertise `resources.subscribe` in its capabilities.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Timeout` if the operation exceeds the configured timeout,
+    /// or `ClientError::ServiceError` if the server rejects the subscription.
+    pub async fn subscribe_resource(&self, uri: &str) -> Result<(), ClientError> {
+        let request = self.peer.subscribe(SubscribeRequestParam {
+            uri: uri.to_string(),
+        });
+
+        self.run_with_timeout(|| format!("subscribe_resource '{}'", uri), request)
+            .await
+    }
+
+    /// Unsubscribe from update notifications for a resource
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Timeout` if the operation exceeds the configured timeout,
+    /// or `ClientError::ServiceError` if the request fails.
+    pub async fn unsubscribe_resource(&self, uri: &str) -> Result<(), ClientError> {
+        let request = self.peer.unsubscribe(UnsubscribeRequestParam {
+            uri: uri.to_string(),
+        });
+
+        self.run_with_timeout(|| format!("unsubscribe_resource '{}'", uri), request)
+            .await
+    }
+
+    /// List all available prompts
+    ///
+    /// Uses the native MCP `prompts/list` method and follows pagination cursors,
+    /// so it works against any MCP server, not only kodegen's prompt tools.
     ///
     /// # Errors
     ///
     /// Returns `ClientError::Timeout` if the operation exceeds the configured timeout,
+    /// or `ClientError::ServiceError` if the MCP request fails.
+    pub async fn list_prompts(&self) -> Result<Vec<Prompt>, ClientError> {
+        self.run_with_timeout(|| "list_prompts".to_string(), self.peer.list_all_prompts())
+            .await
+    }
+
+    /// Get a prompt by name, rendered with the given arguments
+    ///
+    /// Arguments can be any `Serialize` type that produces a JSON object (or
+    /// `()`/`null` for none). MCP prompt arguments are strings, so non-string
+    /// values are rendered to their JSON text before sending.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// #[derive(Serialize)]
+    /// struct ReviewArgs<'a> {
+    ///     language: &'a str,
+    ///     max_findings: u32,
+    /// }
+    ///
+    /// let prompt = client
+    ///     .get_prompt("code_review", ReviewArgs { language: "rust", max_findings: 10 })
+    ///     .await?;
+    /// for message in prompt.messages {
+    ///     println!("{:?}: {:?}", message.role, message.content);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Protocol` if the arguments do not serialize to a JSON object,
+    /// `ClientError::Timeout` if the operation exceeds the configured timeout,
+    /// or `ClientError::ServiceError` if the prompt does not exist or the request fails.
+    pub async fn get_prompt<A>(
+        &self,
+        name: &str,
+        arguments: A,
+    ) -> Result<GetPromptResult, ClientError>
+    where
+        A: serde::Serialize,
+    {
+        let arguments = serde_json::to_value(arguments).map_err(|e| {
+            ClientError::Protocol(format!(
+                "Failed to serialize arguments for prompt '{}': {}",
+                name, e
+            ))
+        })?;
+
+        let request = self.peer.get_prompt(GetPromptRequestParam {
+            name: name.to_string(),
+            arguments: render_prompt_arguments(name, arguments)?,
+        });
+
+        self.run_with_timeout(|| format!("Prompt '{}'", name), request)
+            .await
+    }
+
+    /// Call a tool by name with JSON arguments
+    ///
+    /// If the call exceeds the handle timeout, the server is sent
+    /// `notifications/cancelled` so it stops working on the abandoned request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::InvalidArguments` if the arguments violate the tool's
+    /// `inputSchema` (see [`with_argument_validation`](Self::with_argument_validation)),
+    /// `ClientError::Timeout` if the operation exceeds the configured timeout,
     /// or `ClientError::ServiceError` if the tool call fails or the tool does not exist.
     pub async fn call_tool(
         &self,
         name: &str,
         arguments: serde_json::Value,
     ) -> Result<CallToolResult, ClientError> {
-        let call = self.peer.call_tool(CallToolRequestParam {
-            // name.to_string() allocation is required because CallToolRequestParam
-            // expects Cow<'static, str>. Cannot use borrowed reference from &str parameter
-            // as it doesn't satisfy the 'static lifetime requirement.
-            name: name.to_string().into(),
-            arguments: match arguments {
-                serde_json::Value::Object(map) => Some(map),
-                serde_json::Value::Null => None,
-                other => {
-                    return Err(ClientError::Protocol(format!(
-                        "Tool arguments must be a JSON object or null, got {}",
-                        json_type_name(&other)
-                    )));
-                }
-            },
-        });
+        self.call_tool_with(name, arguments, &CallOptions::default())
+            .await
+    }
 
-        timeout(self.default_timeout, call)
+    /// Call a tool and report the server's progress updates while it runs
+    ///
+    /// The request carries a progress token in its `_meta`, and every
+    /// `notifications/progress` the server sends for that token is forwarded to
+    /// `progress`. The final `CallToolResult` is returned once the tool completes.
+    ///
+    /// Unlike `call_tool()`, the handle timeout is an *idle* timeout here: each
+    /// progress update restarts it, so tools that keep reporting are never cut
+    /// off while silent ones still time out.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    /// tokio::spawn(async move {
+    ///     while let Some(update) = rx.recv().await {
+    ///         println!("{}/{:?} {}", update.progress, update.total,
+    ///                  update.message.unwrap_or_default());
+    ///     }
+    /// });
+    ///
+    /// let result = client
+    ///     .call_tool_with_progress("start_crawl", json!({"url": url}), tx)
+    ///     .await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Timeout` if no progress or result arrives within the
+    /// configured timeout (the server is sent `notifications/cancelled`), or
+    /// `ClientError::ServiceError` if the tool call fails.
+    pub async fn call_tool_with_progress(
+        &self,
+        name: &str,
+        arguments: serde_json::Value,
+        progress: mpsc::UnboundedSender<ToolProgress>,
+    ) -> Result<CallToolResult, ClientError> {
+        self.call_tool_with(name, arguments, &CallOptions::new().on_progress(progress))
             .await
-            .map_err(|_| ClientError::Timeout {
-                operation: format!("Tool '{}'", name),
-                duration: self.default_timeout,
-            })?
-            .map_err(ClientError::from)
+    }
+
+    /// Call a tool with per-call options
+    ///
+    /// Options override the handle's defaults for this call only; see
+    /// [`CallOptions`] for the available knobs.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let options = CallOptions::new()
+    ///     .timeout(Duration::from_secs(30))
+    ///     .meta("traceId", "req-42");
+    /// let result = client.call_tool_with("fs_read_file", args, &options).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::InvalidArguments` if the arguments violate the tool's
+    /// `inputSchema`, `ClientError::Timeout` if the call exceeds the timeout or
+    /// deadline, `ClientError::Cancelled` if the options' cancellation token
+    /// fires (the server is sent `notifications/cancelled` in both cases), or
+    /// `ClientError::ServiceError` if the tool call fails after any retries.
+    pub async fn call_tool_with(
+        &self,
+        name: &str,
+        arguments: serde_json::Value,
+        options: &CallOptions,
+    ) -> Result<CallToolResult, ClientError> {
+        self.check_arguments(name, &arguments, options).await?;
+        let request = ClientRequest::CallToolRequest(CallToolRequest::new(tool_call_params(
+            name, arguments,
+        )?));
+        match self
+            .request_with_options(&|| format!("Tool '{}'", name), request, options)
+            .await?
+        {
+            ServerResult::CallToolResult(result) => Ok(result),
+            _ => Err(ServiceError::UnexpectedResponse.into()),
+        }
+    }
+
+    /// Call a tool and fail if the server flags the result as an error
+    ///
+    /// `call_tool()` returns `Ok` for any result the server sends, including
+    /// ones with `isError: true`. This variant reports those as
+    /// `ClientError::ToolError` so tool failures cannot be mistaken for success.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// match client.call_tool_checked("fs_read_file", json!({"path": path})).await {
+    ///     Ok(result) => println!("{:?}", result.content),
+    ///     Err(ClientError::ToolError { message, .. }) => eprintln!("Read failed: {}", message),
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::ToolError` if the result has `isError: true`, or any
+    /// error from the underlying `call_tool` method.
+    pub async fn call_tool_checked(
+        &self,
+        name: &str,
+        arguments: serde_json::Value,
+    ) -> Result<CallToolResult, ClientError> {
+        let result = self.call_tool(name, arguments).await?;
+        check_tool_result(name, result)
+    }
+
+    /// Start a tool call that can be cancelled while it runs
+    ///
+    /// Returns once the request is sent; await the returned [`ToolCallHandle`] for
+    /// the result. Cancelling `cancellation` (or calling
+    /// [`ToolCallHandle::cancel`]) sends `notifications/cancelled` with the request
+    /// id so the server stops working, and the handle resolves to
+    /// `ClientError::Cancelled`. The handle timeout still applies and cancels the
+    /// request the same way.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let token = CancellationToken::new();
+    /// let call = client
+    ///     .call_tool_cancellable("terminal", json!({"command": "cargo build"}), token.clone())
+    ///     .await?;
+    ///
+    /// // Stop the command if the user navigates away
+    /// ui.on_close(move || token.cancel());
+    ///
+    /// let result = call.await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Protocol` if the arguments are not a JSON object or null,
+    /// `ClientError::InvalidArguments` if they violate the tool's `inputSchema`,
+    /// or `ClientError::ServiceError` if the request cannot be sent.
+    pub async fn call_tool_cancellable(
+        &self,
+        name: &str,
+        arguments: serde_json::Value,
+        cancellation: CancellationToken,
+    ) -> Result<ToolCallHandle, ClientError> {
+        let handle = self.send_tool_call(name, arguments).await?;
+        let request_id = handle.id.clone();
+
+        Ok(ToolCallHandle::spawn(
+            self.peer.clone(),
+            request_id,
+            format!("Tool '{}'", name),
+            cancellation,
+            self.tool_response(name, handle),
+        ))
+    }
+
+    /// Validate tool arguments against the `inputSchema` in the tool catalog
+    ///
+    /// Fetches the catalog first if it is cold, within `CATALOG_FETCH_TIMEOUT`
+    /// or the call's own timeout if shorter. If the server cannot list its
+    /// tools in time, the call is sent unvalidated.
+    async fn check_arguments(
+        &self,
+        name: &str,
+        arguments: &serde_json::Value,
+        options: &CallOptions,
+    ) -> Result<(), ClientError> {
+        if !self.validate_arguments {
+            return Ok(());
+        }
+
+        let timeout = options.timeout.unwrap_or(self.default_timeout);
+        let listing = CallOptions {
+            timeout: Some(timeout.min(CATALOG_FETCH_TIMEOUT)),
+            deadline: options.deadline,
+            cancellation: options.cancellation.clone(),
+            headers: options.headers.clone(),
+            ..CallOptions::default()
+        };
+        let catalog = match self.tool_snapshot(&listing).await {
+            Ok(catalog) => catalog,
+            Err(e @ ClientError::Cancelled { .. }) => return Err(e),
+            Err(e) => {
+                tracing::warn!(
+                    tool = name,
+                    error = %e,
+                    "Sending tool call without argument validation: tools/list failed"
+                );
+                return Ok(());
+            }
+        };
+
+        // Absent arguments are an empty object; other non-objects are rejected
+        // by tool_call_params()
+        match arguments {
+            serde_json::Value::Null => {
+                catalog.validate_arguments(name, &serde_json::Value::Object(JsonObject::new()))
+            }
+            serde_json::Value::Object(_) => catalog.validate_arguments(name, arguments),
+            _ => Ok(()),
+        }
+    }
+
+    /// Send a `tools/call` request without waiting for the response
+    async fn send_tool_call(
+        &self,
+        name: &str,
+        arguments: serde_json::Value,
+    ) -> Result<RequestHandle<RoleClient>, ClientError> {
+        self.check_arguments(name, &arguments, &CallOptions::default())
+            .await?;
+        let request = ClientRequest::CallToolRequest(CallToolRequest::new(tool_call_params(
+            name, arguments,
+        )?));
+        Ok(self
+            .peer
+            .send_request_with_option(request, PeerRequestOptions::no_options())
+            .await?)
+    }
+
+    /// Wait for a tool call's response, cancelling it on the server after a timeout
+    fn tool_response(
+        &self,
+        name: &str,
+        handle: RequestHandle<RoleClient>,
+    ) -> impl Future<Output = Result<CallToolResult, ClientError>> + Send + 'static {
+        let peer = self.peer.clone();
+        let duration = self.default_timeout;
+        let name = name.to_string();
+
+        async move {
+            match timeout(duration, handle.rx).await {
+                Ok(response) => tool_call_result(response),
+                Err(_) => {
+                    notify_cancelled(&peer, handle.id, timeout_reason(duration)).await;
+                    Err(ClientError::Timeout {
+                        operation: format!("Tool '{}'", name),
+                        duration,
+                    })
+                }
+            }
+        }
+    }
+
+    /// Send `request` honoring `options`, retrying per its retry policy
+    async fn request_with_options(
+        &self,
+        operation: &(dyn Fn() -> String + Sync),
+        request: ClientRequest,
+        options: &CallOptions,
+    ) -> Result<ServerResult, ClientError> {
+        let mut retry = 0;
+        loop {
+            let error = match self
+                .attempt_with_options(operation, request.clone(), options)
+                .await
+            {
+                Ok(result) => return Ok(result),
+                Err(error) => error,
+            };
+
+            let delay = options
+                .retry
+                .as_ref()
+                .and_then(|policy| policy.delay_before(retry, &error));
+            match delay {
+                // Don't sleep past the deadline just to fail afterwards
+                Some(delay)
+                    if options
+                        .deadline
+                        .is_none_or(|deadline| Instant::now() + delay < deadline) =>
+                {
+                    tracing::debug!(operation = %operation(), retry, ?delay, error = %error, "Retrying request");
+                    sleep(delay).await;
+                    retry += 1;
+                }
+                _ => return Err(error),
+            }
+        }
+    }
+
+    /// Run a single attempt of a request with per-call options
+    async fn attempt_with_options(
+        &self,
+        operation: &(dyn Fn() -> String + Sync),
+        mut request: ClientRequest,
+        options: &CallOptions,
+    ) -> Result<ServerResult, ClientError> {
+        let Some(duration) = options.attempt_timeout(self.default_timeout) else {
+            return Err(ClientError::Timeout {
+                operation: operation(),
+                duration: Duration::ZERO,
+            });
+        };
+
+        // Our own token is needed to match progress updates
+        let token = options
+            .progress_token
+            .clone()
+            .or_else(|| options.progress.is_some().then(next_progress_token));
+        let mut meta = Meta(options.meta.clone());
+        if let Some(token) = &token {
+            meta.set_progress_token(token.clone());
+        }
+
+        // Subscribe before sending so updates racing the request are not missed
+        let mut progress = options
+            .progress
+            .as_ref()
+            .zip(token.as_ref())
+            .map(|(sink, token)| (self.notifications.subscribe(), sink, token));
+        // Carried in-process to the HTTP transport; extensions are never serialized
+        if !options.headers.is_empty() {
+            request
+                .extensions_mut()
+                .insert(CallHeaders(options.headers.clone()));
+        }
+
+        let handle = self
+            .peer
+            .send_request_with_option(
+                request,
+                PeerRequestOptions {
+                    timeout: None,
+                    meta: (!meta.0.is_empty()).then_some(meta),
+                },
+            )
+            .await?;
+        let request_id = handle.id;
+        let mut response = handle.rx;
+
+        let expiry = sleep(duration);
+        tokio::pin!(expiry);
+        let cancelled = async {
+            match &options.cancellation {
+                Some(cancellation) => cancellation.cancelled().await,
+                None => std::future::pending().await,
+            }
+        };
+        tokio::pin!(cancelled);
+
+        loop {
+            tokio::select! {
+                result = &mut response => {
+                    if let Some((notifications, sink, token)) = &mut progress {
+                        // rmcp dispatches notifications on their own tasks, so let
+                        // updates that raced the response land before returning
+                        tokio::task::yield_now().await;
+                        while let Ok(notification) = notifications.try_recv() {
+                            if let Some(update) = notification.progress_for(token) {
+                                let _ = sink.send(update);
+                            }
+                        }
+                    }
+                    return Ok(result.map_err(|_| ServiceError::TransportClosed)??);
+                }
+                update = next_progress(&mut progress) => {
+                    expiry.as_mut().reset(options.expiry(duration));
+                    if let Some((_, sink, _)) = &progress {
+                        // The caller may have stopped listening; the call still completes
+                        let _ = sink.send(update);
+                    }
+                }
+                () = &mut expiry => {
+                    notify_cancelled(&self.peer, request_id, timeout_reason(duration)).await;
+                    return Err(ClientError::Timeout {
+                        operation: operation(),
+                        duration,
+                    });
+                }
+                () = &mut cancelled => {
+                    notify_cancelled(&self.peer, request_id, None).await;
+                    return Err(ClientError::Cancelled {
+                        operation: operation(),
+                        reason: None,
+                    });
+                }
+            }
+        }
     }
 
     /// Call a tool and deserialize the response to a typed structure
@@ -196,6 +1168,9 @@ impl KodegenClient {
     /// manual JSON extraction with nested Options. Use this with response types from
     /// the `responses` module for better error handling.
     ///
+    /// If the cached tool catalog has an `outputSchema` for the tool, the result
+    /// is validated against it before deserializing.
+    ///
     /// # Example
     ///
     /// ```ignore
@@ -209,8 +1184,10 @@ impl KodegenClient {
     ///
     /// # Errors
     ///
-    /// Returns `ClientError::ParseError` if the response cannot be deserialized,
-    /// or any error from the underlying `call_tool` method.
+    /// Returns `ClientError::ToolError` if the server flags the result as an error,
+    /// `ClientError::OutputSchemaMismatch` if the result violates the tool's
+    /// `outputSchema`, `ClientError::ParseError` if the response cannot be
+    /// deserialized, or any error from the underlying `call_tool` method.
     pub async fn call_tool_typed<T>(
         &self,
         name: &str,
@@ -220,43 +1197,239 @@ impl KodegenClient {
         T: serde::de::DeserializeOwned,
     {
         let result = self.call_tool(name, arguments).await?;
+        let output_schema = self.cached_output_schema(name);
+        parse_tool_result(name, result, output_schema.as_deref())
+    }
 
-        // Extract text content from response - search all items
-        let text_content = result
-            .content
-            .iter()
-            .find_map(|c| c.as_text())
-            .ok_or_else(|| {
-                if result.content.is_empty() {
-                    ClientError::Protocol(format!("Tool '{}' returned empty content array", name))
-                } else {
-                    // Show what content types were returned
-                    let content_types: Vec<_> = result
-                        .content
-                        .iter()
-                        .map(|c| match c.raw {
-                            rmcp::model::RawContent::Text(_) => "text",
-                            rmcp::model::RawContent::Image(_) => "image",
-                            rmcp::model::RawContent::Resource(_) => "resource",
-                            rmcp::model::RawContent::Audio(_) => "audio",
-                            rmcp::model::RawContent::ResourceLink(_) => "resource_link",
-                        })
-                        .collect();
-
-                    ClientError::Protocol(format!(
-                        "Tool '{}' returned {} content item(s) but none were text: [{}]",
-                        name,
-                        result.content.len(),
-                        content_types.join(", ")
-                    ))
-                }
-            })?;
+    /// Call a tool with per-call options and deserialize the response
+    ///
+    /// Combines [`call_tool_with`](Self::call_tool_with) and
+    /// [`call_tool_typed`](Self::call_tool_typed). A schema set with
+    /// [`CallOptions::output_schema`] replaces the one from the tool catalog.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of `call_tool_with()`, plus `ClientError::ToolError` if
+    /// the server flags the result as an error, `ClientError::Protocol` if the
+    /// response has no text content or `ClientError::ParseError` if it does not
+    /// deserialize to `T`.
+    pub async fn call_tool_typed_with<T>(
+        &self,
+        name: &str,
+        arguments: serde_json::Value,
+        options: &CallOptions,
+    ) -> Result<T, ClientError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let result = self.call_tool_with(name, arguments, options).await?;
+        let output_schema = options
+            .output_schema
+            .clone()
+            .or_else(|| self.cached_output_schema(name));
+        parse_tool_result(name, result, output_schema.as_deref())
+    }
 
-        // Deserialize to target type with context
-        serde_json::from_str(&text_content.text).map_err(|e| ClientError::ParseError {
-            tool_name: name.to_string(),
-            source: e,
-        })
+    /// The `outputSchema` of `name` in the cached tool catalog
+    ///
+    /// Never fetches the catalog; it is normally warm after argument validation.
+    fn cached_output_schema(&self, name: &str) -> Option<Arc<JsonObject>> {
+        self.tool_catalog
+            .snapshot()?
+            .tool(name)?
+            .output_schema
+            .clone()
+    }
+
+    /// Call a tool, deserialize the response and check its invariants
+    ///
+    /// Like [`call_tool_typed`](Self::call_tool_typed), then runs
+    /// [`Validate::validate`] on the result so invariants such as count fields
+    /// matching array lengths are enforced on every call. Types without
+    /// invariants opt in with an empty `impl Validate for T {}`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use kodegen_mcp_client::responses::GitHubIssuesResponse;
+    ///
+    /// let issues: GitHubIssuesResponse = client
+    ///     .call_tool_validated("list_issues", json!({"owner": "o", "repo": "r"}))
+    ///     .await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::ValidationError` listing every violated invariant
+    /// (including those of nested structs), or any error from `call_tool_typed()`.
+    pub async fn call_tool_validated<T>(
+        &self,
+        name: &str,
+        arguments: serde_json::Value,
+    ) -> Result<T, ClientError>
+    where
+        T: serde::de::DeserializeOwned + Validate,
+    {
+        let response: T = self.call_tool_typed(name, arguments).await?;
+        validate_response(name, response)
+    }
+
+    /// Call a kodegen tool with typed arguments
+    ///
+    /// The tool's name, argument struct and response type come from its
+    /// [`KodegenTool`] marker in the `requests` module, so argument mistakes
+    /// fail to compile. The response is validated like
+    /// [`call_tool_validated`](Self::call_tool_validated).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use kodegen_mcp_client::requests::{SearchType, StartSearch, StartSearchArgs};
+    ///
+    /// let response = client
+    ///     .invoke::<StartSearch>(StartSearchArgs::new("/project", "*.rs", SearchType::Files))
+    ///     .await?;
+    /// println!("Search session ID: {}", response.session_id);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Protocol` if the arguments do not serialize to a JSON
+    /// object, or any error from `call_tool_validated()`.
+    pub async fn invoke<T: KodegenTool>(&self, args: T::Args) -> Result<T::Output, ClientError> {
+        let arguments = tool_arguments(T::NAME, &args)?;
+        self.call_tool_validated(T::NAME, arguments).await
+    }
+
+    /// Call a kodegen tool with typed arguments and per-call options
+    ///
+    /// Combines [`invoke`](Self::invoke) and [`call_tool_with`](Self::call_tool_with).
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of `invoke()` and `call_tool_typed_with()`.
+    pub async fn invoke_with<T: KodegenTool>(
+        &self,
+        args: T::Args,
+        options: &CallOptions,
+    ) -> Result<T::Output, ClientError> {
+        let arguments = tool_arguments(T::NAME, &args)?;
+        let response = self.call_tool_typed_with(T::NAME, arguments, options).await?;
+        validate_response(T::NAME, response)
+    }
+
+    /// Run a SQL query and decode each row into `T` by column name
+    ///
+    /// Calls `db_execute_sql` through [`invoke`](Self::invoke) and decodes the
+    /// rows with [`ExecuteSqlResponse::rows_as`](responses::database::ExecuteSqlResponse::rows_as).
+    /// Use `invoke::<requests::ExecuteSql>` directly to pick the database or to
+    /// get the column metadata.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// #[derive(Deserialize)]
+    /// struct User {
+    ///     id: i64,
+    ///     email: String,
+    /// }
+    ///
+    /// let users: Vec<User> = client
+    ///     .query_as("SELECT id, email FROM users WHERE active = true")
+    ///     .await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::ParseError` if a row does not deserialize to `T`,
+    /// or any error from `invoke()`.
+    pub async fn query_as<T>(&self, sql: impl Into<String>) -> Result<Vec<T>, ClientError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let response = self
+            .invoke::<requests::ExecuteSql>(requests::ExecuteSqlArgs::new(sql))
+            .await?;
+        response
+            .rows_as()
+            .map_err(|source| ClientError::ParseError {
+                tool_name: tools::database::EXECUTE_SQL.to_string(),
+                source,
+            })
+    }
+
+    /// Start a command with `start_terminal` and return a session driving it
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use kodegen_mcp_client::requests::StartTerminalCommandArgs;
+    ///
+    /// let mut tests = client
+    ///     .start_terminal_command(StartTerminalCommandArgs::new("cargo test"))
+    ///     .await?;
+    /// let code = tests.wait().await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `invoke()`.
+    pub async fn start_terminal_command(
+        &self,
+        args: requests::StartTerminalCommandArgs,
+    ) -> Result<TerminalSession, ClientError> {
+        let started = self.invoke::<requests::StartTerminalCommand>(args).await?;
+        Ok(TerminalSession::new(self.clone(), &started))
+    }
+
+    /// Start a search with `fs_search` and return a session paging through it
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use kodegen_mcp_client::requests::{SearchType, StartSearchArgs};
+    ///
+    /// let mut search = client
+    ///     .start_search(StartSearchArgs::new("/project", "*.rs", SearchType::Files))
+    ///     .await?;
+    /// while let Some(page) = search.next_page().await? {
+    ///     println!("{} more files", page.len());
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `invoke()`.
+    pub async fn start_search(
+        &self,
+        args: requests::StartSearchArgs,
+    ) -> Result<SearchSession, ClientError> {
+        let started = self.invoke::<requests::StartSearch>(args).await?;
+        Ok(SearchSession::new(self.clone(), &started))
+    }
+
+    /// Start a crawl with `scrape_url` and return a session following it
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use kodegen_mcp_client::requests::StartCrawlArgs;
+    ///
+    /// let mut crawl = client
+    ///     .start_crawl(StartCrawlArgs::new("https://example.com"))
+    ///     .await?;
+    /// let state = crawl.wait().await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `invoke()`.
+    pub async fn start_crawl(
+        &self,
+        args: requests::StartCrawlArgs,
+    ) -> Result<CrawlSession, ClientError> {
+        let started = self.invoke::<requests::StartCrawl>(args).await?;
+        Ok(CrawlSession::new(self.clone(), &started))
     }
 }
 
@@ -314,7 +1487,7 @@ impl KodegenClient {
 /// ```
 #[must_use = "Connection must be held to keep MCP service alive"]
 pub struct KodegenConnection {
-    service: RunningService<RoleClient, ClientInfo>,
+    service: RunningService<RoleClient, KodegenClientHandler>,
 }
 
 impl KodegenConnection {
@@ -323,7 +1496,7 @@ impl KodegenConnection {
     /// This is a low-level constructor for creating a connection from an already-initialized
     /// MCP service. Most users should use the transport functions like `create_http_client()`
     /// which handle both service creation and connection setup.
-    pub fn from_service(service: RunningService<RoleClient, ClientInfo>) -> Self {
+    pub fn from_service(service: RunningService<RoleClient, KodegenClientHandler>) -> Self {
         Self { service }
     }
 
@@ -333,7 +1506,75 @@ impl KodegenConnection {
     /// Multiple client handles can coexist and all operate on the same underlying connection.
     #[must_use]
     pub fn client(&self) -> KodegenClient {
-        KodegenClient::from_peer(self.service.peer().clone())
+        KodegenClient::from_peer(self.service.peer().clone(), self.service.service())
+    }
+
+    /// Subscribe to notifications sent by the server
+    ///
+    /// Equivalent to `self.client().subscribe()`; see [`KodegenClient::subscribe`].
+    #[must_use]
+    pub fn notifications(&self) -> NotificationStream {
+        NotificationStream::new(self.service.service().notification_sender().subscribe())
+    }
+
+    /// Replace the workspace roots and notify the server
+    ///
+    /// Subsequent `roots/list` requests return `roots`, and a
+    /// `notifications/roots/list_changed` notification is sent so the server
+    /// re-fetches them. This is the stdio equivalent of updating the
+    /// `X_KODEGEN_PWD`/`X_KODEGEN_GITROOT` headers on HTTP.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let (client, conn) = StdioClientBuilder::new("kodegen")
+    ///     .roots(vec![path_root("/project/frontend", None)])
+    ///     .build()
+    ///     .await?;
+    ///
+    /// // User switched to another package
+    /// conn.set_roots(vec![path_root("/project/backend", None)]).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Protocol` if no roots were registered when connecting
+    /// (the `roots` capability was not advertised), or `ClientError::ServiceError`
+    /// if sending the notification fails.
+    pub async fn set_roots(&self, roots: Vec<Root>) -> Result<(), ClientError> {
+        self.ensure_roots_advertised()?;
+        self.service.service().replace_roots(Arc::new(roots));
+        self.notify_roots_changed().await
+    }
+
+    /// Notify the server that the roots returned by the registered provider changed
+    ///
+    /// Use this with callback-based roots providers whose output changed; for a
+    /// fixed list, prefer `set_roots()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Protocol` if no roots were registered when connecting,
+    /// or `ClientError::ServiceError` if sending the notification fails.
+    pub async fn notify_roots_changed(&self) -> Result<(), ClientError> {
+        self.ensure_roots_advertised()?;
+        self.service
+            .peer()
+            .notify_roots_list_changed()
+            .await
+            .map_err(ClientError::from)
+    }
+
+    /// The server only expects `roots/list_changed` if we advertised `roots`
+    fn ensure_roots_advertised(&self) -> Result<(), ClientError> {
+        if self.service.service().has_roots() {
+            Ok(())
+        } else {
+            Err(ClientError::Protocol(
+                "Roots capability was not advertised; register roots on the builder before connecting"
+                    .to_string(),
+            ))
+        }
     }
 
     /// Graceful shutdown with proper MCP protocol cancellation
@@ -0,0 +1,165 @@
+//! Server notification fan-out
+//!
+//! Every notification the server sends is converted to a typed
+//! [`ServerNotification`] and broadcast to all subscribers. Each subscriber gets
+//! its own [`NotificationStream`], so cloned `KodegenClient` handles can listen
+//! independently (e.g. one task invalidating caches, another showing logs).
+
+use rmcp::model::{LoggingLevel, ProgressNotificationParam, ProgressToken, RequestId};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::sync::broadcast;
+use tokio_stream::{
+    Stream,
+    wrappers::{BroadcastStream, errors::BroadcastStreamRecvError},
+};
+
+/// Number of notifications buffered per subscriber before the oldest are dropped
+pub(crate) const NOTIFICATION_CHANNEL_CAPACITY: usize = 256;
+
+/// Typed notification received from the MCP server
+#[derive(Debug, Clone, PartialEq)]
+pub enum ServerNotification {
+    /// `notifications/tools/list_changed`: the tool catalog must be re-fetched
+    ToolListChanged,
+    /// `notifications/resources/list_changed`: the resource list must be re-fetched
+    ResourceListChanged,
+    /// `notifications/resources/updated`: a subscribed resource changed
+    ResourceUpdated {
+        /// URI of the updated resource
+        uri: String,
+    },
+    /// `notifications/prompts/list_changed`: the prompt list must be re-fetched
+    PromptListChanged,
+    /// `notifications/message`: a server log entry
+    LoggingMessage {
+        /// Severity of the entry
+        level: LoggingLevel,
+        /// Name of the logger that produced the entry, if any
+        logger: Option<String>,
+        /// Arbitrary JSON payload
+        data: serde_json::Value,
+    },
+    /// `notifications/progress`: progress of a long-running request
+    Progress {
+        /// Token identifying the request this progress belongs to
+        token: ProgressToken,
+        /// Progress so far; increases with every update
+        progress: f64,
+        /// Total amount of work, if known
+        total: Option<f64>,
+        /// Human-readable status message
+        message: Option<String>,
+    },
+    /// `notifications/cancelled`: the server cancelled one of our requests
+    Cancelled {
+        /// ID of the cancelled request
+        request_id: RequestId,
+        /// Reason given by the server
+        reason: Option<String>,
+    },
+}
+
+/// Progress update for a single in-flight request
+///
+/// Delivered by `KodegenClient::call_tool_with_progress()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolProgress {
+    /// Progress so far; increases with every update
+    pub progress: f64,
+    /// Total amount of work, if known
+    pub total: Option<f64>,
+    /// Human-readable status message
+    pub message: Option<String>,
+}
+
+impl ServerNotification {
+    /// Extract the progress update if this notification belongs to `token`
+    pub(crate) fn progress_for(self, token: &ProgressToken) -> Option<ToolProgress> {
+        match self {
+            Self::Progress {
+                token: received,
+                progress,
+                total,
+                message,
+            } if received == *token => Some(ToolProgress {
+                progress,
+                total,
+                message,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl From<ProgressNotificationParam> for ServerNotification {
+    fn from(param: ProgressNotificationParam) -> Self {
+        Self::Progress {
+            token: param.progress_token,
+            progress: param.progress,
+            total: param.total,
+            message: param.message,
+        }
+    }
+}
+
+/// Stream of notifications for one subscriber
+///
+/// Created by `KodegenConnection::notifications()` or `KodegenClient::subscribe()`.
+/// Only notifications received after subscribing are delivered. If a subscriber
+/// falls more than 256 notifications behind, the oldest are skipped (with a
+/// warning logged) rather than blocking the connection.
+///
+/// The stream ends once the connection and every client handle are dropped.
+///
+/// # Example
+///
+/// ```ignore
+/// use tokio_stream::StreamExt;
+/// use kodegen_mcp_client::ServerNotification;
+///
+/// let mut notifications = client.subscribe();
+/// while let Some(notification) = notifications.next().await {
+///     match notification {
+///         ServerNotification::ToolListChanged => tool_cache.clear(),
+///         ServerNotification::LoggingMessage { level, data, .. } => ui.log(level, data),
+///         _ => {}
+///     }
+/// }
+/// ```
+pub struct NotificationStream {
+    inner: BroadcastStream<ServerNotification>,
+}
+
+impl NotificationStream {
+    pub(crate) fn new(receiver: broadcast::Receiver<ServerNotification>) -> Self {
+        Self {
+            inner: BroadcastStream::new(receiver),
+        }
+    }
+}
+
+impl std::fmt::Debug for NotificationStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NotificationStream").finish_non_exhaustive()
+    }
+}
+
+impl Stream for NotificationStream {
+    type Item = ServerNotification;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match Pin::new(&mut self.inner).poll_next(cx) {
+                Poll::Ready(Some(Ok(notification))) => return Poll::Ready(Some(notification)),
+                Poll::Ready(Some(Err(BroadcastStreamRecvError::Lagged(skipped)))) => {
+                    tracing::warn!(skipped, "Notification subscriber lagged; skipping");
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -0,0 +1,285 @@
+//! Per-call request options
+//!
+//! `KodegenClient` carries handle-wide defaults (currently the timeout). A
+//! [`CallOptions`] value overrides them for a single request and adds knobs that
+//! only make sense per call: `_meta` fields, progress reporting, cancellation,
+//! retries and extra HTTP headers.
+
+use crate::{ClientError, ToolProgress};
+use reqwest::header::{HeaderMap, HeaderValue, IntoHeaderName};
+use rmcp::model::{JsonObject, NumberOrString, ProgressToken, Tool};
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+use tokio::{
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+use tokio_util::sync::CancellationToken;
+
+/// Options for a single request
+///
+/// Pass to `call_tool_with()`, `call_tool_typed_with()` or `list_tools_with()`.
+/// Every field is optional; unset fields fall back to the client handle's
+/// defaults, so `CallOptions::default()` behaves exactly like the plain methods.
+///
+/// # Example
+///
+/// ```ignore
+/// use kodegen_mcp_client::{CallOptions, RetryPolicy, X_KODEGEN_PWD};
+///
+/// let options = CallOptions::new()
+///     .timeout(Duration::from_secs(30))
+///     .meta("traceId", "req-42")
+///     .retry(RetryPolicy::new(2))
+///     .header(X_KODEGEN_PWD, HeaderValue::from_static("/project/backend"));
+///
+/// let result = client.call_tool_with("fs_read_file", args, &options).await?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CallOptions {
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) deadline: Option<Instant>,
+    pub(crate) meta: JsonObject,
+    pub(crate) progress_token: Option<ProgressToken>,
+    pub(crate) progress: Option<mpsc::UnboundedSender<ToolProgress>>,
+    pub(crate) cancellation: Option<CancellationToken>,
+    pub(crate) retry: Option<RetryPolicy>,
+    pub(crate) headers: HeaderMap,
+    pub(crate) output_schema: Option<Arc<JsonObject>>,
+}
+
+impl CallOptions {
+    /// Create options that use the client handle's defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the handle timeout for this request
+    ///
+    /// With a retry policy, the timeout applies to each attempt.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Fail the request (including retries) once `deadline` has passed
+    ///
+    /// Combined with [`timeout`](Self::timeout), whichever expires first wins.
+    #[must_use]
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Add a field to the request's `_meta` object
+    ///
+    /// Use [`progress_token`](Self::progress_token) rather than setting
+    /// `progressToken` here.
+    #[must_use]
+    pub fn meta(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.meta.insert(key.into(), value.into());
+        self
+    }
+
+    /// Use a specific progress token instead of a generated one
+    ///
+    /// Useful for correlating the request with `ServerNotification::Progress`
+    /// events seen through `KodegenClient::subscribe()`.
+    #[must_use]
+    pub fn progress_token(mut self, token: impl Into<String>) -> Self {
+        self.progress_token = Some(ProgressToken(NumberOrString::String(token.into().into())));
+        self
+    }
+
+    /// Forward the server's progress updates for this request to `sender`
+    ///
+    /// Each update restarts the timeout, so tools that keep reporting progress
+    /// are not cut off. The deadline is never extended.
+    #[must_use]
+    pub fn on_progress(mut self, sender: mpsc::UnboundedSender<ToolProgress>) -> Self {
+        self.progress = Some(sender);
+        self
+    }
+
+    /// Cancel the request when `token` is cancelled
+    ///
+    /// The server is sent `notifications/cancelled` and the call fails with
+    /// `ClientError::Cancelled`.
+    #[must_use]
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Retry failed attempts according to `policy`
+    #[must_use]
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Add an HTTP header sent with this request only
+    ///
+    /// Headers override the connection's default headers of the same name.
+    /// Ignored on stdio connections.
+    #[must_use]
+    pub fn header(mut self, name: impl IntoHeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Add multiple HTTP headers sent with this request only
+    ///
+    /// Ignored on stdio connections.
+    #[must_use]
+    pub fn headers(mut self, headers: HeaderMap) -> Self {
+        self.headers.extend(headers);
+        self
+    }
+
+    /// Validate typed results against `schema` before deserializing
+    ///
+    /// Used by `call_tool_typed_with()`; mismatches are reported as
+    /// `ClientError::OutputSchemaMismatch` with the JSON pointer of each violation.
+    #[must_use]
+    pub fn output_schema(mut self, schema: Arc<JsonObject>) -> Self {
+        self.output_schema = Some(schema);
+        self
+    }
+
+    /// Validate typed results against the `outputSchema` a tool declared in `tools/list`
+    ///
+    /// Has no effect if the tool declares no output schema.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let tools = client.list_tools().await?;
+    /// let tool = tools.iter().find(|t| t.name == "fs_read_file").unwrap();
+    ///
+    /// let options = CallOptions::new().output_schema_from(tool);
+    /// let response: ReadFileResponse = client
+    ///     .call_tool_typed_with("fs_read_file", args, &options)
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub fn output_schema_from(mut self, tool: &Tool) -> Self {
+        if let Some(schema) = &tool.output_schema {
+            self.output_schema = Some(Arc::clone(schema));
+        }
+        self
+    }
+
+    /// Timeout for the next attempt, bounded by the deadline
+    ///
+    /// Returns `None` once the deadline has passed.
+    pub(crate) fn attempt_timeout(&self, default_timeout: Duration) -> Option<Duration> {
+        let timeout = self.timeout.unwrap_or(default_timeout);
+        match self.deadline {
+            Some(deadline) => {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                (!remaining.is_zero()).then(|| timeout.min(remaining))
+            }
+            None => Some(timeout),
+        }
+    }
+
+    /// When a timer started now for `timeout` should fire, capped at the deadline
+    pub(crate) fn expiry(&self, timeout: Duration) -> Instant {
+        let expiry = Instant::now() + timeout;
+        self.deadline
+            .map_or(expiry, |deadline| expiry.min(deadline))
+    }
+}
+
+/// Retry policy for [`CallOptions::retry`]
+///
+/// Failed attempts are retried with exponential backoff while the error matches
+/// the policy's predicate and the deadline (if any) allows. By default transient
+/// transport send failures and timeouts are retried.
+///
+/// Retrying a tool call re-runs the tool, so only enable this for tools that are
+/// safe to repeat.
+///
+/// # Example
+///
+/// ```ignore
+/// // Up to 3 retries: after 200ms, 400ms, then 800ms
+/// let policy = RetryPolicy::new(3).backoff(Duration::from_millis(200), Duration::from_secs(2));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    retry_on: fn(&ClientError) -> bool,
+}
+
+impl RetryPolicy {
+    /// Retry up to `max_retries` times, starting with a 100ms backoff capped at 5s
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            retry_on: Self::is_transient,
+        }
+    }
+
+    /// Set the delay before the first retry and the cap for later ones
+    ///
+    /// The delay doubles after every retry until it reaches `max`.
+    #[must_use]
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Replace the predicate deciding which errors are retried
+    #[must_use]
+    pub fn retry_on(mut self, predicate: fn(&ClientError) -> bool) -> Self {
+        self.retry_on = predicate;
+        self
+    }
+
+    /// Default predicate: transport send failures and timeouts
+    pub fn is_transient(error: &ClientError) -> bool {
+        matches!(
+            error,
+            ClientError::Timeout { .. }
+                | ClientError::ServiceError(rmcp::ServiceError::TransportSend(_))
+        )
+    }
+
+    /// Backoff before retry number `retry` (0-based), or `None` if out of retries
+    pub(crate) fn delay_before(&self, retry: u32, error: &ClientError) -> Option<Duration> {
+        if retry >= self.max_retries || !(self.retry_on)(error) {
+            return None;
+        }
+        let factor = 2u32.saturating_pow(retry);
+        Some(
+            self.initial_backoff
+                .saturating_mul(factor)
+                .min(self.max_backoff),
+        )
+    }
+}
+
+/// HTTP headers for a single request, from `CallOptions::header()`
+///
+/// Stored in the request's rmcp extensions, which travel with the message to
+/// the transport but are never serialized, so the MCP payload is unchanged.
+/// The HTTP transport adds them to the POST carrying the request.
+#[derive(Debug, Clone)]
+pub(crate) struct CallHeaders(pub(crate) HeaderMap);
+
+/// Generate a progress token unique within this process
+pub(crate) fn next_progress_token() -> ProgressToken {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    let id = NEXT.fetch_add(1, Ordering::Relaxed);
+    ProgressToken(NumberOrString::String(format!("kodegen-{id}").into()))
+}
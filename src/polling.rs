@@ -0,0 +1,73 @@
+//! Plumbing shared by the polled server-side sessions
+//!
+//! [`SearchSession`](crate::SearchSession) and
+//! [`CrawlSession`](crate::CrawlSession) both fetch pages of items while a
+//! background job runs on the server, and both end that job when they are
+//! dropped before it finishes.
+
+use crate::ClientError;
+use std::{collections::VecDeque, future::Future};
+use tokio_stream::Stream;
+
+/// A session that hands out a job's items a page at a time
+pub(crate) trait Paged {
+    type Item;
+
+    /// Wait for the next non-empty page, or `None` once the job has ended
+    fn next_items(
+        &mut self,
+    ) -> impl Future<Output = Result<Option<Vec<Self::Item>>, ClientError>> + Send;
+}
+
+/// Stream the items of every remaining page of `session`
+///
+/// Ends when `next_items` returns `None`. The first error ends the stream
+/// after being yielded.
+pub(crate) fn stream_items<S: Paged + Send>(
+    session: &mut S,
+) -> impl Stream<Item = Result<S::Item, ClientError>> + '_ {
+    futures::stream::unfold(
+        (session, VecDeque::new(), false),
+        |(session, mut buffered, failed)| async move {
+            if let Some(item) = buffered.pop_front() {
+                return Some((Ok(item), (session, buffered, false)));
+            }
+            if failed {
+                return None;
+            }
+            match session.next_items().await {
+                Ok(Some(page)) => {
+                    buffered.extend(page);
+                    let item = buffered.pop_front()?;
+                    Some((Ok(item), (session, buffered, false)))
+                }
+                Ok(None) => None,
+                Err(e) => Some((Err(e), (session, buffered, true))),
+            }
+        },
+    )
+}
+
+/// End a dropped session's server-side job from a background task
+///
+/// `Drop` cannot await, so `cleanup` is spawned on the current Tokio runtime
+/// and nobody waits for it: it never runs if the session is dropped outside a
+/// runtime, and may not run if the runtime is shutting down. `session` names
+/// the job in the warnings logged when the cleanup cannot be sent or fails.
+pub(crate) fn spawn_cleanup<F, T>(session: String, cleanup: F)
+where
+    F: Future<Output = Result<T, ClientError>> + Send + 'static,
+{
+    let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+        tracing::warn!(
+            %session,
+            "Session dropped outside a Tokio runtime; left running on the server"
+        );
+        return;
+    };
+    runtime.spawn(async move {
+        if let Err(e) = cleanup.await {
+            tracing::warn!(error = %e, %session, "Failed to end dropped session");
+        }
+    });
+}
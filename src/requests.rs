@@ -0,0 +1,1289 @@
+//! Typed argument structures for MCP tool calls
+//!
+//! Each tool is described by a marker type implementing [`KodegenTool`], which
+//! ties together the tool's name, its argument struct and its response type.
+//! Calling it through [`KodegenClient::invoke`](crate::KodegenClient::invoke)
+//! turns misnamed or mistyped arguments into compile errors instead of
+//! server-side failures:
+//!
+//! ```ignore
+//! use kodegen_mcp_client::requests::{ListIssues, ListIssuesArgs};
+//!
+//! let response = client
+//!     .invoke::<ListIssues>(ListIssuesArgs::new("myorg", "myrepo"))
+//!     .await?;
+//! println!("{} open issues", response.count);
+//! ```
+//!
+//! # Coverage
+//!
+//! Markers exist only for tools whose response shape is typed in
+//! [`responses`](crate::responses), because `Output` must be a real type
+//! checked against what the server returns:
+//!
+//! - filesystem: read, write, edit, list, move, file info and search
+//! - git: every tool with a typed response (29 of 42; the rest are listed in
+//!   the Git section below)
+//! - terminal, claude agent, sequential thinking, `config_get` and `prompt_get`
+//! - database: `db_execute_sql`, `db_list_schemas`, `db_table_schema` and
+//!   `db_pool_stats`
+//! - `scrape_url` crawls
+//! - GitHub: `github_list_issues`, `github_search_issues` and
+//!   `github_get_issue_comments`, the tools whose wrapper format is documented
+//!
+//! The remaining tools (browser, memory, process, introspection, reasoner,
+//! most GitHub tools, among others) have no documented response shape here,
+//! and guessing one would hide wire-format drift instead of catching it. Call
+//! them with `call_tool` and the names from [`tools`](crate::tools), or
+//! generate markers from the server's own schemas with
+//! [`codegen`](crate::codegen).
+
+use crate::responses::{citescrape::*, database::*, filesystem::*, terminal::*, *};
+use crate::tools;
+use crate::validation::Validate;
+use serde::{Serialize, de::DeserializeOwned};
+
+/// A kodegen tool with typed arguments and response
+pub trait KodegenTool {
+    /// Tool name as registered by the server
+    const NAME: &'static str;
+
+    /// Arguments, serialized to the call's JSON object
+    type Args: Serialize;
+
+    /// Response, deserialized and validated from the call result
+    type Output: DeserializeOwned + Validate;
+}
+
+/// Declare a marker type implementing [`KodegenTool`]
+macro_rules! kodegen_tool {
+    ($(#[$meta:meta])* $tool:ident, $name:expr, $args:ty => $output:ty) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $tool;
+
+        impl KodegenTool for $tool {
+            const NAME: &'static str = $name;
+            type Args = $args;
+            type Output = $output;
+        }
+    };
+}
+
+// ============================================================================
+// Filesystem
+// ============================================================================
+
+kodegen_tool!(
+    /// `fs_read_file`: read a file, optionally a window of its lines
+    ReadFile, tools::filesystem::READ_FILE, ReadFileArgs => ReadFileResponse
+);
+
+/// Arguments for `fs_read_file`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReadFileArgs {
+    pub path: String,
+    /// First line to read; negative values count from the end of the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+    /// Maximum number of lines to read
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<usize>,
+}
+
+impl ReadFileArgs {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `fs_write_file`: write or append to a file
+    WriteFile, tools::filesystem::WRITE_FILE, WriteFileArgs => WriteFileResponse
+);
+
+/// Arguments for `fs_write_file`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WriteFileArgs {
+    pub path: String,
+    pub content: String,
+    pub mode: WriteMode,
+}
+
+impl WriteFileArgs {
+    pub fn new(path: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            content: content.into(),
+            mode: WriteMode::Rewrite,
+        }
+    }
+
+    /// Split a write into calls of at most `line_limit` lines each
+    ///
+    /// The first call rewrites the file and the rest append to it, so the
+    /// server's `file_write_line_limit` is never exceeded. Empty content
+    /// yields a single call that truncates the file.
+    #[must_use]
+    pub fn chunked(path: impl Into<String>, content: &str, line_limit: usize) -> Vec<Self> {
+        let path = path.into();
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        if lines.is_empty() {
+            return vec![Self::new(path, "")];
+        }
+        lines
+            .chunks(line_limit.max(1))
+            .enumerate()
+            .map(|(idx, chunk)| Self {
+                path: path.clone(),
+                content: chunk.concat(),
+                mode: if idx == 0 {
+                    WriteMode::Rewrite
+                } else {
+                    WriteMode::Append
+                },
+            })
+            .collect()
+    }
+}
+
+kodegen_tool!(
+    /// `fs_edit_block`: replace text in a file
+    EditBlock, tools::filesystem::EDIT_BLOCK, EditBlockArgs => EditBlockResponse
+);
+
+/// Arguments for `fs_edit_block`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EditBlockArgs {
+    pub file_path: String,
+    pub old_string: String,
+    pub new_string: String,
+    /// Number of occurrences expected to be replaced (default 1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_replacements: Option<usize>,
+}
+
+impl EditBlockArgs {
+    pub fn new(
+        file_path: impl Into<String>,
+        old_string: impl Into<String>,
+        new_string: impl Into<String>,
+    ) -> Self {
+        Self {
+            file_path: file_path.into(),
+            old_string: old_string.into(),
+            new_string: new_string.into(),
+            expected_replacements: None,
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `fs_list_directory`: list a directory's entries
+    ListDirectory, tools::filesystem::LIST_DIRECTORY, ListDirectoryArgs => ListDirectoryResponse
+);
+
+/// Arguments for `fs_list_directory`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ListDirectoryArgs {
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_hidden: Option<bool>,
+}
+
+impl ListDirectoryArgs {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `fs_move_file`: move or rename a file or directory
+    MoveFile, tools::filesystem::MOVE_FILE, MoveFileArgs => MoveFileResponse
+);
+
+/// Arguments for `fs_move_file`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MoveFileArgs {
+    pub source: String,
+    pub destination: String,
+}
+
+impl MoveFileArgs {
+    pub fn new(source: impl Into<String>, destination: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            destination: destination.into(),
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `fs_get_file_info`: describe a file or directory
+    GetFileInfo, tools::filesystem::GET_FILE_INFO, GetFileInfoArgs => FileInfoResponse
+);
+
+/// Arguments for `fs_get_file_info`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GetFileInfoArgs {
+    pub path: String,
+}
+
+impl GetFileInfoArgs {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+kodegen_tool!(
+    /// `fs_search`: start a background file or content search
+    StartSearch, tools::filesystem::SEARCH, StartSearchArgs => StartSearchResponse
+);
+
+/// What a search matches against
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchType {
+    /// File names
+    #[default]
+    Files,
+    /// File contents
+    Content,
+}
+
+/// Arguments for `fs_search`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartSearchArgs {
+    /// Directory to search from
+    pub path: String,
+    /// Glob (for file names) or regex (for contents) to match
+    pub pattern: String,
+    pub search_type: SearchType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_pattern: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_case: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<usize>,
+}
+
+impl StartSearchArgs {
+    pub fn new(
+        path: impl Into<String>,
+        pattern: impl Into<String>,
+        search_type: SearchType,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            pattern: pattern.into(),
+            search_type,
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `fs_search`: fetch a running search's results from an offset
+    SearchResults, tools::filesystem::SEARCH, SearchResultsArgs => SearchResultsResponse
+);
+
+/// Arguments for fetching results with `fs_search`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(tag = "action", rename = "results", rename_all = "camelCase")]
+pub struct SearchResultsArgs {
+    pub session_id: String,
+    /// Number of results already read; the page starts after them
+    pub offset: u64,
+    /// Maximum number of results on the page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<u64>,
+}
+
+impl SearchResultsArgs {
+    pub fn new(session_id: impl Into<String>, offset: u64) -> Self {
+        Self {
+            session_id: session_id.into(),
+            offset,
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `fs_search`: stop a running search
+    StopSearch, tools::filesystem::SEARCH, StopSearchArgs => StopSearchResponse
+);
+
+/// Arguments for stopping a search with `fs_search`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(tag = "action", rename = "stop", rename_all = "camelCase")]
+pub struct StopSearchArgs {
+    pub session_id: String,
+}
+
+impl StopSearchArgs {
+    pub fn new(session_id: impl Into<String>) -> Self {
+        Self {
+            session_id: session_id.into(),
+        }
+    }
+}
+
+// ============================================================================
+// Git
+// ============================================================================
+
+kodegen_tool!(
+    /// `git_status`: working tree and index changes
+    GitStatus, tools::git::STATUS, GitRepoArgs => git::GitStatusResponse
+);
+
+/// Arguments for git tools that only need the repository
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitRepoArgs {
+    /// Path to the repository or one of its worktrees
+    pub path: String,
+}
+
+impl GitRepoArgs {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+kodegen_tool!(
+    /// `git_log`: commit history, newest first
+    GitLog, tools::git::LOG, GitLogArgs => git::GitLogResponse
+);
+
+/// Arguments for `git_log` and `git_history`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitLogArgs {
+    pub path: String,
+    /// Maximum number of commits
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_count: Option<u32>,
+    /// Only commits touching this path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_filter: Option<String>,
+}
+
+impl GitLogArgs {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `git_history`: history of the commits touching `path_filter`
+    GitHistory, tools::git::HISTORY, GitLogArgs => git::GitLogResponse
+);
+
+kodegen_tool!(
+    /// `git_show`: a single commit
+    GitShow, tools::git::SHOW, GitShowArgs => git::GitCommitInfo
+);
+
+/// Arguments for `git_show`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitShowArgs {
+    pub path: String,
+    /// Commit, branch or tag to show
+    pub revision: String,
+}
+
+impl GitShowArgs {
+    pub fn new(path: impl Into<String>, revision: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            revision: revision.into(),
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `git_diff`: changes between the working tree, index or revisions
+    GitDiff, tools::git::DIFF, GitDiffArgs => git::GitDiffResponse
+);
+
+/// Arguments for `git_diff`
+///
+/// With neither revision set, diffs the working tree against the index.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitDiffArgs {
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+impl GitDiffArgs {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `git_commit`: commit the staged changes
+    GitCommit, tools::git::COMMIT, GitCommitArgs => git::GitCommitResponse
+);
+
+/// Arguments for `git_commit`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitCommitArgs {
+    pub path: String,
+    pub message: String,
+    /// Stage all tracked changes first, like `git commit -a`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all: Option<bool>,
+}
+
+impl GitCommitArgs {
+    pub fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `git_branch_list`: local and remote-tracking branches
+    GitBranchList, tools::git::BRANCH_LIST, GitRepoArgs => git::GitBranchListResponse
+);
+
+kodegen_tool!(
+    /// `git_branch_create`: create a branch
+    GitBranchCreate, tools::git::BRANCH_CREATE, GitBranchCreateArgs => git::GitBranchResponse
+);
+
+/// Arguments for `git_branch_create`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitBranchCreateArgs {
+    pub path: String,
+    pub branch: String,
+    /// Commit or branch to start from (default HEAD)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_branch: Option<String>,
+    /// Check the branch out after creating it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkout: Option<bool>,
+}
+
+impl GitBranchCreateArgs {
+    pub fn new(path: impl Into<String>, branch: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            branch: branch.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `git_worktree_list`: the repository's worktrees
+    GitWorktreeList, tools::git::WORKTREE_LIST, GitRepoArgs => git::GitWorktreeListResponse
+);
+
+kodegen_tool!(
+    /// `git_worktree_add`: check a branch out into a new worktree
+    GitWorktreeAdd, tools::git::WORKTREE_ADD, GitWorktreeAddArgs => git::GitWorktreeResponse
+);
+
+/// Arguments for `git_worktree_add`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitWorktreeAddArgs {
+    pub path: String,
+    /// Where to create the worktree
+    pub worktree_path: String,
+    /// Branch to check out (default: a new branch named after the directory)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+}
+
+impl GitWorktreeAddArgs {
+    pub fn new(path: impl Into<String>, worktree_path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            worktree_path: worktree_path.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `git_branch_delete`: delete a branch
+    GitBranchDelete, tools::git::BRANCH_DELETE, GitBranchDeleteArgs => git::GitBranchResponse
+);
+
+/// Arguments for `git_branch_delete`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitBranchDeleteArgs {
+    pub path: String,
+    pub branch: String,
+    /// Delete even if the branch is not merged
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+}
+
+impl GitBranchDeleteArgs {
+    pub fn new(path: impl Into<String>, branch: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            branch: branch.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `git_branch_rename`: rename a branch
+    GitBranchRename, tools::git::BRANCH_RENAME, GitBranchRenameArgs => git::GitBranchResponse
+);
+
+/// Arguments for `git_branch_rename`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitBranchRenameArgs {
+    pub path: String,
+    pub old_name: String,
+    pub new_name: String,
+}
+
+impl GitBranchRenameArgs {
+    pub fn new(
+        path: impl Into<String>,
+        old_name: impl Into<String>,
+        new_name: impl Into<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            old_name: old_name.into(),
+            new_name: new_name.into(),
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `git_checkout`: check out a branch, tag or commit
+    GitCheckout, tools::git::CHECKOUT, GitCheckoutArgs => git::GitCheckoutResponse
+);
+
+/// Arguments for `git_checkout`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitCheckoutArgs {
+    pub path: String,
+    /// Branch, tag or commit to check out
+    pub target: String,
+    /// Create `target` as a new branch first
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create: Option<bool>,
+}
+
+impl GitCheckoutArgs {
+    pub fn new(path: impl Into<String>, target: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            target: target.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `git_tag_list`: the repository's tags
+    GitTagList, tools::git::TAG_LIST, GitRepoArgs => git::GitTagListResponse
+);
+
+kodegen_tool!(
+    /// `git_remote_list`: the repository's remotes
+    GitRemoteList, tools::git::REMOTE_LIST, GitRepoArgs => git::GitRemoteListResponse
+);
+
+kodegen_tool!(
+    /// `git_stash_list`: the stash stack, newest first
+    GitStashList, tools::git::STASH_LIST, GitRepoArgs => git::GitStashListResponse
+);
+
+kodegen_tool!(
+    /// `git_merge`: merge a branch into the current one
+    GitMerge, tools::git::MERGE, GitMergeArgs => git::GitMergeResponse
+);
+
+/// Arguments for `git_merge`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitMergeArgs {
+    pub path: String,
+    /// Branch or commit to merge
+    pub branch: String,
+}
+
+impl GitMergeArgs {
+    pub fn new(path: impl Into<String>, branch: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            branch: branch.into(),
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `git_rebase`: rebase the current branch onto another
+    GitRebase, tools::git::REBASE, GitRebaseArgs => git::GitMergeResponse
+);
+
+/// Arguments for `git_rebase`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitRebaseArgs {
+    pub path: String,
+    /// Branch or commit to rebase onto
+    pub onto: String,
+}
+
+impl GitRebaseArgs {
+    pub fn new(path: impl Into<String>, onto: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            onto: onto.into(),
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `git_cherry_pick`: apply a commit onto the current branch
+    GitCherryPick, tools::git::CHERRY_PICK, GitCherryPickArgs => git::GitMergeResponse
+);
+
+/// Arguments for `git_cherry_pick`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitCherryPickArgs {
+    pub path: String,
+    pub commit: String,
+}
+
+impl GitCherryPickArgs {
+    pub fn new(path: impl Into<String>, commit: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            commit: commit.into(),
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `git_pull`: fetch from a remote and merge into the current branch
+    GitPull, tools::git::PULL, GitSyncArgs => git::GitMergeResponse
+);
+
+kodegen_tool!(
+    /// `git_push`: push to a remote
+    GitPush, tools::git::PUSH, GitSyncArgs => git::GitSyncResponse
+);
+
+kodegen_tool!(
+    /// `git_fetch`: fetch from a remote
+    GitFetch, tools::git::FETCH, GitSyncArgs => git::GitSyncResponse
+);
+
+/// Arguments for `git_pull`, `git_push` and `git_fetch`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitSyncArgs {
+    pub path: String,
+    /// Remote name (default: the branch's upstream remote)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+    /// Branch or refspec (default: the current branch)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+}
+
+impl GitSyncArgs {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `git_init`: create a repository
+    GitInit, tools::git::INIT, GitInitArgs => git::GitRepositoryResponse
+);
+
+/// Arguments for `git_init`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitInitArgs {
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bare: Option<bool>,
+}
+
+impl GitInitArgs {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `git_clone`: clone a repository
+    GitClone, tools::git::CLONE, GitCloneArgs => git::GitRepositoryResponse
+);
+
+/// Arguments for `git_clone`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitCloneArgs {
+    pub url: String,
+    /// Directory to clone into
+    pub path: String,
+    /// Branch to check out (default: the remote's HEAD)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+}
+
+impl GitCloneArgs {
+    pub fn new(url: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            path: path.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `git_open`: open the repository at a path
+    GitOpen, tools::git::OPEN, GitRepoArgs => git::GitRepositoryResponse
+);
+
+kodegen_tool!(
+    /// `git_discover`: find the repository containing a path
+    GitDiscover, tools::git::DISCOVER, GitRepoArgs => git::GitRepositoryResponse
+);
+
+kodegen_tool!(
+    /// `git_worktree_remove`: remove a linked worktree
+    GitWorktreeRemove, tools::git::WORKTREE_REMOVE, GitWorktreeArgs => git::GitWorktreeResponse
+);
+
+kodegen_tool!(
+    /// `git_worktree_lock`: protect a linked worktree from pruning
+    GitWorktreeLock, tools::git::WORKTREE_LOCK, GitWorktreeArgs => git::GitWorktreeResponse
+);
+
+kodegen_tool!(
+    /// `git_worktree_unlock`: allow a linked worktree to be pruned again
+    GitWorktreeUnlock, tools::git::WORKTREE_UNLOCK, GitWorktreeArgs => git::GitWorktreeResponse
+);
+
+/// Arguments for the git tools acting on an existing worktree
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitWorktreeArgs {
+    pub path: String,
+    pub worktree_path: String,
+    /// Why the worktree is locked (`git_worktree_lock` only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl GitWorktreeArgs {
+    pub fn new(path: impl Into<String>, worktree_path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            worktree_path: worktree_path.into(),
+            ..Self::default()
+        }
+    }
+}
+
+// `git_add`, `git_reset`, `git_revert`, `git_tag`, `git_tag_create`,
+// `git_stash`, `git_stash_apply`, `git_stash_pop`, `git_remote_add`,
+// `git_remote_remove`, `git_config_get`, `git_config_set` and
+// `git_worktree_prune` have no typed response yet; call them by name through
+// `call_tool`.
+
+// ============================================================================
+// Terminal
+// ============================================================================
+
+kodegen_tool!(
+    /// `start_terminal`: start a command in a new terminal
+    StartTerminalCommand, tools::terminal::START_TERMINAL, StartTerminalCommandArgs => StartTerminalCommandResponse
+);
+
+/// Arguments for `start_terminal`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StartTerminalCommandArgs {
+    pub command: String,
+    /// Shell to run the command in, instead of the configured default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shell: Option<String>,
+    /// How long to wait for initial output, in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+}
+
+impl StartTerminalCommandArgs {
+    pub fn new(command: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `terminal`: read a command's output from an offset
+    ReadTerminalOutput, tools::terminal::TERMINAL, ReadTerminalOutputArgs => TerminalOutputResponse
+);
+
+/// Arguments for reading output with `terminal`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(tag = "action", rename = "read")]
+pub struct ReadTerminalOutputArgs {
+    pub pid: i64,
+    /// Where to start reading; pass the previous read's `next_offset`
+    pub offset: u64,
+    /// Maximum amount of output to return
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<u64>,
+}
+
+impl ReadTerminalOutputArgs {
+    pub fn new(pid: i64, offset: u64) -> Self {
+        Self {
+            pid,
+            offset,
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `terminal`: write to a command's stdin
+    SendTerminalInput, tools::terminal::TERMINAL, SendTerminalInputArgs => SendInputResponse
+);
+
+/// Arguments for sending input with `terminal`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(tag = "action", rename = "input")]
+pub struct SendTerminalInputArgs {
+    pub pid: i64,
+    /// Text written as is; include `\n` to submit a line
+    pub input: String,
+}
+
+impl SendTerminalInputArgs {
+    pub fn new(pid: i64, input: impl Into<String>) -> Self {
+        Self {
+            pid,
+            input: input.into(),
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `terminal`: kill a running command
+    KillTerminal, tools::terminal::TERMINAL, KillTerminalArgs => KillTerminalResponse
+);
+
+/// Arguments for killing a command with `terminal`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(tag = "action", rename = "kill")]
+pub struct KillTerminalArgs {
+    pub pid: i64,
+}
+
+impl KillTerminalArgs {
+    pub fn new(pid: i64) -> Self {
+        Self { pid }
+    }
+}
+
+// ============================================================================
+// Database
+// ============================================================================
+
+kodegen_tool!(
+    /// `db_execute_sql`: run a SQL statement
+    ExecuteSql, tools::database::EXECUTE_SQL, ExecuteSqlArgs => ExecuteSqlResponse
+);
+
+/// Arguments for `db_execute_sql`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ExecuteSqlArgs {
+    pub query: String,
+    /// Database to run against, instead of the connection default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database: Option<String>,
+}
+
+impl ExecuteSqlArgs {
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `db_list_schemas`: list the database's schemas
+    ListSchemas, tools::database::LIST_SCHEMAS, ListSchemasArgs => ListSchemasResponse
+);
+
+/// Arguments for `db_list_schemas`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ListSchemasArgs {}
+
+kodegen_tool!(
+    /// `db_table_schema`: describe a table's columns
+    TableSchema, tools::database::TABLE_SCHEMA, TableSchemaArgs => TableSchemaResponse
+);
+
+/// Arguments for `db_table_schema`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TableSchemaArgs {
+    pub table_name: String,
+    /// Schema containing the table, instead of the default search path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+}
+
+impl TableSchemaArgs {
+    pub fn new(table_name: impl Into<String>) -> Self {
+        Self {
+            table_name: table_name.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `db_pool_stats`: connection pool usage
+    PoolStats, tools::database::POOL_STATS, PoolStatsArgs => PoolStatsResponse
+);
+
+/// Arguments for `db_pool_stats`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PoolStatsArgs {}
+
+// ============================================================================
+// Web Crawling
+// ============================================================================
+
+kodegen_tool!(
+    /// `scrape_url`: start a background crawl
+    StartCrawl, tools::citescrape::SCRAPE_URL, StartCrawlArgs => StartCrawlResponse
+);
+
+/// Arguments for `scrape_url`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StartCrawlArgs {
+    pub url: String,
+    /// How many links deep to follow from `url`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_depth: Option<u32>,
+    /// Maximum number of pages to crawl
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl StartCrawlArgs {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `scrape_url`: poll a crawl's state and the pages crawled since an offset
+    CrawlStatus, tools::citescrape::SCRAPE_URL, CrawlStatusArgs => CrawlStatusResponse
+);
+
+/// Arguments for polling a crawl with `scrape_url`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(tag = "action", rename = "status")]
+pub struct CrawlStatusArgs {
+    pub crawl_id: String,
+    /// Number of pages already read; the poll returns the pages after them
+    pub offset: u64,
+}
+
+impl CrawlStatusArgs {
+    pub fn new(crawl_id: impl Into<String>, offset: u64) -> Self {
+        Self {
+            crawl_id: crawl_id.into(),
+            offset,
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `scrape_url`: search the content a crawl has fetched so far
+    SearchCrawl, tools::citescrape::SCRAPE_URL, SearchCrawlArgs => CrawlSearchResponse
+);
+
+/// Arguments for searching a crawl with `scrape_url`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(tag = "action", rename = "search")]
+pub struct SearchCrawlArgs {
+    pub crawl_id: String,
+    pub query: String,
+    /// Maximum number of hits
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl SearchCrawlArgs {
+    pub fn new(crawl_id: impl Into<String>, query: impl Into<String>) -> Self {
+        Self {
+            crawl_id: crawl_id.into(),
+            query: query.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `scrape_url`: cancel a running crawl
+    CancelCrawl, tools::citescrape::SCRAPE_URL, CancelCrawlArgs => CancelCrawlResponse
+);
+
+/// Arguments for cancelling a crawl with `scrape_url`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(tag = "action", rename = "cancel")]
+pub struct CancelCrawlArgs {
+    pub crawl_id: String,
+}
+
+impl CancelCrawlArgs {
+    pub fn new(crawl_id: impl Into<String>) -> Self {
+        Self {
+            crawl_id: crawl_id.into(),
+        }
+    }
+}
+
+// ============================================================================
+// Claude Agents
+// ============================================================================
+
+kodegen_tool!(
+    /// `claude_agent`: spawn Claude sub-agent sessions
+    SpawnClaudeAgent, tools::claude_agent::CLAUDE_AGENT, SpawnClaudeAgentArgs => SpawnClaudeAgentResponse
+);
+
+/// Arguments for `claude_agent`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SpawnClaudeAgentArgs {
+    pub prompt: String,
+    /// Number of agents to spawn with the same prompt
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub worker_count: Option<u32>,
+}
+
+impl SpawnClaudeAgentArgs {
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: prompt.into(),
+            ..Self::default()
+        }
+    }
+}
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+kodegen_tool!(
+    /// `config_get`: read the server configuration
+    GetConfig, tools::config::GET, GetConfigArgs => GetConfigResponse
+);
+
+/// Arguments for `config_get`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GetConfigArgs {}
+
+// ============================================================================
+// Prompts
+// ============================================================================
+
+kodegen_tool!(
+    /// `prompt_get`: fetch a prompt template unrendered
+    GetPrompt, tools::prompt::GET, GetPromptArgs => GetPromptResponse
+);
+
+/// Arguments for `prompt_get` without rendering
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GetPromptArgs {
+    pub name: String,
+}
+
+impl GetPromptArgs {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+kodegen_tool!(
+    /// `prompt_get`: render a prompt template with parameters
+    RenderPrompt, tools::prompt::GET, RenderPromptArgs => RenderPromptResponse
+);
+
+/// Arguments for `prompt_get` with template parameters
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RenderPromptArgs {
+    pub name: String,
+    pub parameters: serde_json::Map<String, serde_json::Value>,
+}
+
+impl RenderPromptArgs {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set a template parameter
+    #[must_use]
+    pub fn parameter(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.parameters.insert(name.into(), value.into());
+        self
+    }
+}
+
+// ============================================================================
+// Sequential Thinking
+// ============================================================================
+
+kodegen_tool!(
+    /// `sequential_thinking`: record one step of a reasoning chain
+    SequentialThinking, tools::sequential_thinking::SEQUENTIAL_THINKING, SequentialThinkingArgs => SequentialThinkingResponse
+);
+
+/// Arguments for `sequential_thinking`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SequentialThinkingArgs {
+    pub thought: String,
+    pub thought_number: u32,
+    pub total_thoughts: u32,
+    pub next_thought_needed: bool,
+    /// Session to continue; omitted to start a new one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_revision: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revises_thought: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch_from_thought: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch_id: Option<String>,
+}
+
+// ============================================================================
+// GitHub
+// ============================================================================
+
+kodegen_tool!(
+    /// `github_list_issues`: list a repository's issues
+    ListIssues, tools::github::LIST_ISSUES, ListIssuesArgs => GitHubIssuesResponse
+);
+
+/// Arguments for `github_list_issues`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ListIssuesArgs {
+    pub owner: String,
+    pub repo: String,
+    /// `"open"`, `"closed"` or `"all"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+}
+
+impl ListIssuesArgs {
+    pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self {
+        Self {
+            owner: owner.into(),
+            repo: repo.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `github_search_issues`: search issues with GitHub search syntax
+    SearchIssues, tools::github::SEARCH_ISSUES, SearchIssuesArgs => GitHubIssuesResponse
+);
+
+/// Arguments for `github_search_issues`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SearchIssuesArgs {
+    pub query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+}
+
+impl SearchIssuesArgs {
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            ..Self::default()
+        }
+    }
+}
+
+kodegen_tool!(
+    /// `github_get_issue_comments`: list the comments on an issue
+    GetIssueComments, tools::github::GET_ISSUE_COMMENTS, GetIssueCommentsArgs => GitHubCommentsResponse
+);
+
+/// Arguments for `github_get_issue_comments`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GetIssueCommentsArgs {
+    pub owner: String,
+    pub repo: String,
+    pub issue_number: u64,
+}
+
+impl GetIssueCommentsArgs {
+    pub fn new(owner: impl Into<String>, repo: impl Into<String>, issue_number: u64) -> Self {
+        Self {
+            owner: owner.into(),
+            repo: repo.into(),
+            issue_number,
+        }
+    }
+}
@@ -10,31 +10,35 @@
 use serde::Deserialize;
 use crate::validation::*;
 
+pub mod citescrape;
+pub mod database;
+pub mod filesystem;
+pub mod git;
+pub mod terminal;
+
 /// Response from starting a web crawl session
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct StartCrawlResponse {
     /// The crawl ID for this crawl session
-    #[serde(deserialize_with = "deserialize_non_empty_string")]
+    #[validate(non_empty)]
     pub crawl_id: String,
 }
 
 /// Response from starting a file/content search
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct StartSearchResponse {
     /// The session ID for this search
     /// Supports both `sessionId` (camelCase) and `session_id` (`snake_case`)
-    #[serde(
-        alias = "sessionId",
-        deserialize_with = "deserialize_non_empty_string"
-    )]
+    #[serde(alias = "sessionId")]
+    #[validate(non_empty)]
     pub session_id: String,
 }
 
 /// Response from spawning a Claude agent sub-session
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct SpawnClaudeAgentResponse {
     /// The session IDs for spawned Claude agents
-    #[serde(deserialize_with = "deserialize_vec_non_empty_strings")]
+    #[validate(len_eq = "worker_count", custom = "non_empty_strings")]
     pub session_ids: Vec<String>,
 
     /// Number of workers spawned
@@ -45,24 +49,11 @@ pub struct SpawnClaudeAgentResponse {
     pub agents: Vec<serde_json::Value>,
 }
 
-impl Validate for SpawnClaudeAgentResponse {
-    fn validate(&self) -> Result<(), String> {
-        if self.session_ids.len() != self.worker_count as usize {
-            return Err(count_mismatch_error(
-                "worker_count",
-                self.worker_count as usize,
-                self.session_ids.len(),
-            ));
-        }
-        Ok(())
-    }
-}
-
 /// Response from starting a terminal command
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct StartTerminalCommandResponse {
     /// The process ID (PID) of the started command
-    #[serde(deserialize_with = "deserialize_positive_i64")]
+    #[validate(range(min = 1))]
     pub pid: i64,
 
     /// Optional status information
@@ -71,7 +62,7 @@ pub struct StartTerminalCommandResponse {
 }
 
 /// Response from getting prompt template
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct GetPromptResponse {
     pub name: String,
     pub metadata: PromptMetadata,
@@ -80,7 +71,7 @@ pub struct GetPromptResponse {
 }
 
 /// Response from rendering prompt with parameters
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct RenderPromptResponse {
     pub name: String,
     pub content: String,
@@ -108,7 +99,7 @@ pub struct ParameterDefinition {
 }
 
 /// Response from `get_config` tool
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct GetConfigResponse {
     pub blocked_commands: Vec<String>,
     pub default_shell: String,
@@ -162,9 +153,9 @@ pub struct MemoryInfo {
 }
 
 /// Response from `sequential_thinking` tool
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct SequentialThinkingResponse {
-    #[serde(deserialize_with = "deserialize_non_empty_string")]
+    #[validate(non_empty)]
     pub session_id: String,
     pub thought_number: u32,
     pub total_thoughts: u32,
@@ -178,11 +169,11 @@ pub struct SequentialThinkingResponse {
 // ============================================================================
 
 /// GitHub user information
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, Validate)]
 pub struct GitHubUser {
-    #[serde(deserialize_with = "deserialize_positive_u64")]
+    #[validate(range(min = 1))]
     pub id: u64,
-    #[serde(deserialize_with = "deserialize_non_empty_string")]
+    #[validate(non_empty)]
     pub login: String,
     #[serde(default)]
     pub name: Option<String>,
@@ -193,11 +184,12 @@ pub struct GitHubUser {
 }
 
 /// GitHub repository information
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, Validate)]
 pub struct GitHubRepository {
     pub id: u64,
     pub name: String,
     pub full_name: String,
+    #[validate(nested)]
     pub owner: GitHubUser,
     #[serde(default)]
     pub description: Option<String>,
@@ -229,7 +221,7 @@ pub struct GitHubLabel {
 }
 
 /// GitHub issue
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct GitHubIssue {
     pub id: u64,
     pub number: u64,
@@ -237,8 +229,10 @@ pub struct GitHubIssue {
     #[serde(default)]
     pub body: Option<String>,
     pub state: String,
+    #[validate(nested)]
     pub user: GitHubUser,
     #[serde(default)]
+    #[validate(nested)]
     pub assignees: Vec<GitHubUser>,
     #[serde(default)]
     pub labels: Vec<GitHubLabel>,
@@ -248,10 +242,11 @@ pub struct GitHubIssue {
 }
 
 /// GitHub comment (on issues or PRs)
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct GitHubComment {
     pub id: u64,
     pub body: String,
+    #[validate(nested)]
     pub user: GitHubUser,
     pub html_url: Option<String>,
     pub created_at: String,
@@ -259,16 +254,17 @@ pub struct GitHubComment {
 }
 
 /// GitHub branch reference
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, Validate)]
 pub struct GitHubBranchRef {
     #[serde(rename = "ref")]
     pub ref_field: String,
     pub sha: String,
+    #[validate(nested)]
     pub repo: GitHubRepository,
 }
 
 /// GitHub pull request
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct GitHubPullRequest {
     pub id: u64,
     pub number: u64,
@@ -276,8 +272,11 @@ pub struct GitHubPullRequest {
     #[serde(default)]
     pub body: Option<String>,
     pub state: String,
+    #[validate(nested)]
     pub user: GitHubUser,
+    #[validate(nested)]
     pub head: GitHubBranchRef,
+    #[validate(nested)]
     pub base: GitHubBranchRef,
     pub html_url: Option<String>,
     #[serde(default)]
@@ -288,9 +287,10 @@ pub struct GitHubPullRequest {
 }
 
 /// GitHub pull request review
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct GitHubReview {
     pub id: u64,
+    #[validate(nested)]
     pub user: GitHubUser,
     #[serde(default)]
     pub body: Option<String>,
@@ -328,13 +328,15 @@ pub struct GitHubCommitRef {
 }
 
 /// GitHub commit (full version)
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct GitHubCommit {
     pub sha: String,
     pub commit: GitHubCommitDetail,
     #[serde(default)]
+    #[validate(nested)]
     pub author: Option<GitHubUser>,
     #[serde(default)]
+    #[validate(nested)]
     pub committer: Option<GitHubUser>,
     pub html_url: Option<String>,
 }
@@ -381,28 +383,16 @@ pub struct GitHubSearchResults<T> {
 /// Used by:
 /// - `packages/github/src/tool/list_issues.rs:131`
 /// - `packages/github/src/tool/search_issues.rs:106`
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct GitHubIssuesResponse {
     /// Total number of issues returned
     pub count: u64,
 
     /// Complete GitHub Issue objects with all fields populated
+    #[validate(len_eq = "count", nested)]
     pub issues: Vec<GitHubIssue>,
 }
 
-impl Validate for GitHubIssuesResponse {
-    fn validate(&self) -> Result<(), String> {
-        if self.count as usize != self.issues.len() {
-            return Err(count_mismatch_error(
-                "count",
-                self.count as usize,
-                self.issues.len(),
-            ));
-        }
-        Ok(())
-    }
-}
-
 /// Response wrapper for `get_issue_comments` tool
 ///
 /// Our GitHub tools return this custom format instead of standard GitHub API format.
@@ -412,28 +402,16 @@ impl Validate for GitHubIssuesResponse {
 ///
 /// Used by:
 /// - `packages/github/src/tool/get_issue_comments.rs`
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct GitHubCommentsResponse {
     /// Total number of comments returned
     pub count: u64,
 
     /// Complete GitHub Comment objects with all fields populated
+    #[validate(len_eq = "count", nested)]
     pub comments: Vec<GitHubComment>,
 }
 
-impl Validate for GitHubCommentsResponse {
-    fn validate(&self) -> Result<(), String> {
-        if self.count as usize != self.comments.len() {
-            return Err(count_mismatch_error(
-                "count",
-                self.count as usize,
-                self.comments.len(),
-            ));
-        }
-        Ok(())
-    }
-}
-
 /// GitHub code search result
 #[derive(Debug, Deserialize)]
 pub struct GitHubCodeResult {
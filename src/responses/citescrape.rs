@@ -0,0 +1,108 @@
+//! Typed responses for the follow-up actions of `scrape_url`
+//!
+//! A crawl started with `scrape_url` is identified by its crawl ID. Status
+//! polls return the pages crawled since an offset, so each page is reported
+//! once; [`CrawlSession`](crate::CrawlSession) drives these calls.
+
+use crate::validation::*;
+use serde::Deserialize;
+
+/// Lifecycle state of a crawl
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CrawlState {
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+    #[serde(other)]
+    Other,
+}
+
+impl CrawlState {
+    /// Whether the crawl has stopped, successfully or not
+    #[must_use]
+    pub fn is_finished(self) -> bool {
+        !matches!(self, Self::Running)
+    }
+}
+
+/// A page fetched by a crawl
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct CrawledPage {
+    #[validate(non_empty)]
+    pub url: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Links followed from the start URL to reach this page (0 for the start URL)
+    #[serde(default)]
+    pub depth: u32,
+    /// HTTP status code of the response
+    #[validate(range(min = 100, max = 599))]
+    pub status: u16,
+    /// Size of the extracted content in bytes
+    #[serde(default)]
+    pub bytes: Option<u64>,
+}
+
+impl CrawledPage {
+    /// Whether the page was fetched with a 2xx status
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Response from polling a crawl's status
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct CrawlStatusResponse {
+    #[validate(non_empty)]
+    pub crawl_id: String,
+    pub state: CrawlState,
+    /// Pages crawled since the requested offset
+    #[serde(default)]
+    #[validate(nested)]
+    pub pages: Vec<CrawledPage>,
+    /// Pages crawled so far, across all polls
+    pub pages_crawled: u64,
+    /// URLs discovered but not crawled yet
+    #[serde(default)]
+    pub pages_queued: Option<u64>,
+    /// Why the crawl failed
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// A match from searching a crawl's content
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct CrawlSearchHit {
+    #[validate(non_empty)]
+    pub url: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Excerpt of the page around the match
+    #[serde(default)]
+    pub snippet: String,
+    /// Relevance score; higher is better
+    #[serde(default)]
+    pub score: Option<f64>,
+}
+
+/// Response from searching a crawl's content
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct CrawlSearchResponse {
+    #[validate(non_empty)]
+    pub crawl_id: String,
+    pub count: u64,
+    #[validate(len_eq = "count", nested)]
+    pub hits: Vec<CrawlSearchHit>,
+}
+
+/// Response from cancelling a crawl
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct CancelCrawlResponse {
+    #[validate(non_empty)]
+    pub crawl_id: String,
+    /// Whether the crawl was still running and has been cancelled
+    pub cancelled: bool,
+}
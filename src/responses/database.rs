@@ -0,0 +1,283 @@
+//! Typed responses for the database tools
+//!
+//! `db_execute_sql` returns its rows either as arrays of values in column
+//! order or as objects keyed by column name. [`ExecuteSqlResponse::records`]
+//! presents both as objects, and [`ExecuteSqlResponse::rows_as`] decodes them
+//! into any `T: DeserializeOwned` by column name, which is what
+//! [`KodegenClient::query_as`](crate::KodegenClient::query_as) builds on.
+
+use crate::validation::*;
+use serde::{
+    Deserialize,
+    de::{DeserializeOwned, Error as _},
+};
+use serde_json::{Map, Value};
+
+/// Broad family of a column's SQL type, for code that does not care about
+/// the exact database-specific type name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlType {
+    Boolean,
+    Integer,
+    Float,
+    Decimal,
+    Text,
+    Binary,
+    Date,
+    Time,
+    Timestamp,
+    Json,
+    Uuid,
+    Array,
+    Other,
+}
+
+impl SqlType {
+    /// Classify a type name such as `"VARCHAR(255)"`, `"int8"` or `"timestamp with time zone"`
+    #[must_use]
+    pub fn from_name(name: &str) -> Self {
+        let name = name.trim().to_ascii_lowercase();
+        if name.ends_with("[]") || name.starts_with('_') || name.starts_with("array") {
+            return Self::Array;
+        }
+        // Drop length, precision and modifiers: "numeric(10, 2) unsigned" -> "numeric"
+        let base = name
+            .split(|c: char| c == '(' || c.is_whitespace())
+            .next()
+            .unwrap_or_default();
+        match base {
+            "bool" | "boolean" | "bit" => Self::Boolean,
+            "tinyint" | "smallint" | "mediumint" | "int" | "integer" | "bigint" | "int2"
+            | "int4" | "int8" | "serial" | "bigserial" | "smallserial" => Self::Integer,
+            "real" | "float" | "float4" | "float8" | "double" => Self::Float,
+            "numeric" | "decimal" | "money" => Self::Decimal,
+            "char" | "character" | "varchar" | "nchar" | "nvarchar" | "text" | "tinytext"
+            | "mediumtext" | "longtext" | "string" | "citext" | "name" | "enum" => Self::Text,
+            "bytea" | "blob" | "tinyblob" | "mediumblob" | "longblob" | "binary" | "varbinary" => {
+                Self::Binary
+            }
+            "date" => Self::Date,
+            "time" | "timetz" => Self::Time,
+            "timestamp" | "timestamptz" | "datetime" => Self::Timestamp,
+            "json" | "jsonb" => Self::Json,
+            "uuid" => Self::Uuid,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Response from `db_list_schemas`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct ListSchemasResponse {
+    #[validate(custom = "non_empty_strings")]
+    pub schemas: Vec<String>,
+}
+
+/// A column of a table, from `db_table_schema`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct ColumnInfo {
+    #[validate(non_empty)]
+    pub name: String,
+    /// Database-specific type name, e.g. `"character varying(255)"`
+    #[validate(non_empty)]
+    pub data_type: String,
+    #[serde(default)]
+    pub nullable: bool,
+    /// Default value expression
+    #[serde(default)]
+    pub default_value: Option<String>,
+    #[serde(default)]
+    pub primary_key: bool,
+}
+
+impl ColumnInfo {
+    /// Family of the column's type
+    #[must_use]
+    pub fn sql_type(&self) -> SqlType {
+        SqlType::from_name(&self.data_type)
+    }
+}
+
+/// Response from `db_table_schema`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct TableSchemaResponse {
+    #[validate(non_empty)]
+    pub table: String,
+    #[serde(default)]
+    pub schema: Option<String>,
+    #[validate(nested)]
+    pub columns: Vec<ColumnInfo>,
+}
+
+impl TableSchemaResponse {
+    /// Look up a column by name
+    #[must_use]
+    pub fn column(&self, name: &str) -> Option<&ColumnInfo> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+
+    /// Columns making up the primary key
+    pub fn primary_key(&self) -> impl Iterator<Item = &ColumnInfo> {
+        self.columns.iter().filter(|c| c.primary_key)
+    }
+}
+
+/// A column of a query result
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct ResultColumn {
+    #[validate(non_empty)]
+    pub name: String,
+    /// Database-specific type name, when the server reports it
+    #[serde(default)]
+    pub data_type: Option<String>,
+}
+
+impl ResultColumn {
+    /// Family of the column's type, if known
+    #[must_use]
+    pub fn sql_type(&self) -> Option<SqlType> {
+        self.data_type.as_deref().map(SqlType::from_name)
+    }
+}
+
+/// Response from `db_execute_sql`
+///
+/// Statements that return no rows (`INSERT`, `UPDATE`, DDL) have empty
+/// `columns` and `rows` and report `rows_affected` instead.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExecuteSqlResponse {
+    #[serde(default)]
+    pub columns: Vec<ResultColumn>,
+    /// Rows as arrays of values in column order, or as objects keyed by column name
+    #[serde(default)]
+    pub rows: Vec<Value>,
+    /// Number of rows returned, when the server reports it
+    #[serde(default)]
+    pub row_count: Option<u64>,
+    /// Number of rows changed by a data-modifying statement
+    #[serde(default)]
+    pub rows_affected: Option<u64>,
+}
+
+impl ExecuteSqlResponse {
+    /// Column names in result order
+    pub fn column_names(&self) -> impl Iterator<Item = &str> {
+        self.columns.iter().map(|c| c.name.as_str())
+    }
+
+    /// Each row as an object keyed by column name
+    ///
+    /// Array rows are zipped with `columns`; object rows are returned as is.
+    /// Any other row becomes an empty object (such rows fail validation).
+    pub fn records(&self) -> impl Iterator<Item = Map<String, Value>> + '_ {
+        self.rows.iter().map(|row| match row {
+            Value::Object(record) => record.clone(),
+            Value::Array(values) => self
+                .column_names()
+                .map(str::to_string)
+                .zip(values.iter().cloned())
+                .collect(),
+            _ => Map::new(),
+        })
+    }
+
+    /// Decode each row into `T` by column name
+    ///
+    /// `T`'s fields are matched against column names, so serde attributes
+    /// such as `rename` and `default` apply as usual, and extra columns are
+    /// ignored unless `T` denies unknown fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first row's deserialization error, with the row index in
+    /// the message.
+    pub fn rows_as<T: DeserializeOwned>(&self) -> Result<Vec<T>, serde_json::Error> {
+        self.records()
+            .enumerate()
+            .map(|(index, record)| {
+                serde_json::from_value(Value::Object(record))
+                    .map_err(|e| serde_json::Error::custom(format!("row {}: {}", index, e)))
+            })
+            .collect()
+    }
+}
+
+impl Validate for ExecuteSqlResponse {
+    fn validate_into(&self, errors: &mut ValidationErrors) {
+        errors.nested("columns", &self.columns);
+        if let Some(count) = self.row_count
+            && count as usize != self.rows.len()
+        {
+            errors.add(
+                "rows",
+                count_mismatch_error("row_count", count as usize, self.rows.len()),
+            );
+        }
+        errors.nested(
+            "rows",
+            &RowShapes {
+                rows: &self.rows,
+                width: self.columns.len(),
+            },
+        );
+    }
+}
+
+/// Checks that rows are objects or arrays with one value per column
+struct RowShapes<'a> {
+    rows: &'a [Value],
+    width: usize,
+}
+
+impl Validate for RowShapes<'_> {
+    fn validate_into(&self, errors: &mut ValidationErrors) {
+        for (index, row) in self.rows.iter().enumerate() {
+            match row {
+                Value::Object(_) => {}
+                Value::Array(values) if values.len() == self.width => {}
+                Value::Array(values) => errors.add(
+                    &index.to_string(),
+                    format!(
+                        "row has {} values but there are {} columns",
+                        values.len(),
+                        self.width
+                    ),
+                ),
+                _ => errors.add(&index.to_string(), "row must be an array or an object"),
+            }
+        }
+    }
+}
+
+/// Response from `db_pool_stats`
+#[derive(Debug, Clone, Deserialize)]
+pub struct PoolStatsResponse {
+    /// Open connections, idle and in use
+    pub size: u32,
+    /// Open connections not currently in use
+    pub idle: u32,
+    /// Upper bound on open connections
+    pub max_size: u32,
+}
+
+impl PoolStatsResponse {
+    /// Open connections currently in use
+    #[must_use]
+    pub fn in_use(&self) -> u32 {
+        self.size.saturating_sub(self.idle)
+    }
+}
+
+impl Validate for PoolStatsResponse {
+    fn validate_into(&self, errors: &mut ValidationErrors) {
+        if self.idle > self.size {
+            errors.add("idle", above_maximum_error("idle", self.idle, self.size));
+        }
+        if self.size > self.max_size {
+            errors.add(
+                "size",
+                above_maximum_error("size", self.size, self.max_size),
+            );
+        }
+    }
+}
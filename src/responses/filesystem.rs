@@ -0,0 +1,274 @@
+//! Typed responses for the filesystem tools
+//!
+//! Reads and writes are bounded by the server's line limits
+//! ([`GetConfigResponse::file_read_line_limit`](super::GetConfigResponse) and
+//! `file_write_line_limit`): a read returns at most `file_read_line_limit`
+//! lines and reports the window it covers, so longer files are read in several
+//! calls (see [`ReadFileResponse::next_offset`]); writes longer than
+//! `file_write_line_limit` lines are split into one rewrite followed by appends
+//! (see [`WriteFileArgs::chunked`](crate::requests::WriteFileArgs::chunked)).
+
+use crate::validation::*;
+use serde::{Deserialize, Serialize};
+
+/// Response from `fs_read_file`
+///
+/// Lines are numbered from 1; `start_line..=end_line` is the window returned
+/// in `content`. A file read in full has `start_line == 1` and
+/// `end_line == total_lines`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReadFileResponse {
+    pub path: String,
+    pub content: String,
+    /// First line included in `content` (1-based)
+    #[serde(default = "first_line")]
+    pub start_line: usize,
+    /// Last line included in `content` (1-based, inclusive)
+    pub end_line: usize,
+    /// Number of lines in the whole file
+    pub total_lines: usize,
+    /// Whether the server cut the read short at its line limit
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+fn first_line() -> usize {
+    1
+}
+
+impl ReadFileResponse {
+    /// Number of lines in `content`
+    #[must_use]
+    pub fn lines_returned(&self) -> usize {
+        self.end_line
+            .saturating_add(1)
+            .saturating_sub(self.start_line)
+    }
+
+    /// Whether `content` covers the whole file
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.start_line <= 1 && self.end_line >= self.total_lines
+    }
+
+    /// Offset for the read that continues after this one, if lines remain
+    ///
+    /// Pass it as `ReadFileArgs::offset` (0-based) to read the next window.
+    #[must_use]
+    pub fn next_offset(&self) -> Option<usize> {
+        (self.end_line < self.total_lines).then_some(self.end_line)
+    }
+
+    /// Whether the server honored a `file_read_line_limit` of `limit`
+    #[must_use]
+    pub fn within_line_limit(&self, limit: usize) -> bool {
+        self.lines_returned() <= limit
+    }
+}
+
+impl Validate for ReadFileResponse {
+    fn validate_into(&self, errors: &mut ValidationErrors) {
+        // An empty file reads as start_line 1, end_line 0
+        if self.start_line == 0 {
+            errors.add("start_line", below_minimum_error("start_line", 0, 1));
+        }
+        if self.end_line.saturating_add(1) < self.start_line {
+            errors.add(
+                "end_line",
+                below_minimum_error("end_line", self.end_line, self.start_line - 1),
+            );
+        }
+        if self.end_line > self.total_lines {
+            errors.add(
+                "end_line",
+                above_maximum_error("end_line", self.end_line, self.total_lines),
+            );
+        }
+    }
+}
+
+/// How `fs_write_file` applies its content
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WriteMode {
+    /// Replace the file's contents
+    #[default]
+    Rewrite,
+    /// Add to the end of the file
+    Append,
+}
+
+/// Response from `fs_write_file`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct WriteFileResponse {
+    #[validate(non_empty)]
+    pub path: String,
+    pub lines_written: usize,
+    #[serde(default)]
+    pub mode: WriteMode,
+    /// Set when the content exceeded `file_write_line_limit`
+    #[serde(default)]
+    pub warning: Option<String>,
+}
+
+/// Response from `fs_edit_block`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct EditBlockResponse {
+    #[validate(non_empty)]
+    pub path: String,
+    /// Number of occurrences replaced
+    pub replacements: usize,
+    /// Unified diff of the change, when the server reports one
+    #[serde(default)]
+    pub diff: Option<String>,
+}
+
+impl EditBlockResponse {
+    /// The diff's added and removed lines, skipping headers and context
+    ///
+    /// Only the lines before the first `@@` hunk header are treated as file
+    /// headers, so removed or added content starting with `--`/`++` is kept.
+    #[must_use]
+    pub fn changed_lines(&self) -> Vec<DiffLine<'_>> {
+        self.diff
+            .as_deref()
+            .unwrap_or_default()
+            .lines()
+            .skip_while(|line| !line.starts_with("@@"))
+            .filter(|line| !line.starts_with("@@"))
+            .filter_map(|line| {
+                if let Some(text) = line.strip_prefix('+') {
+                    Some(DiffLine::Added(text))
+                } else {
+                    line.strip_prefix('-').map(DiffLine::Removed)
+                }
+            })
+            .collect()
+    }
+}
+
+/// A changed line in an edit diff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLine<'a> {
+    Added(&'a str),
+    Removed(&'a str),
+}
+
+/// Kind of a directory entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EntryKind {
+    File,
+    Directory,
+    Symlink,
+    #[serde(other)]
+    Other,
+}
+
+/// An entry in a directory listing
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct DirectoryEntry {
+    #[validate(non_empty)]
+    pub name: String,
+    pub path: String,
+    pub kind: EntryKind,
+    /// Size in bytes (files only)
+    #[serde(default)]
+    pub size: Option<u64>,
+    /// Last modification time (RFC 3339)
+    #[serde(default)]
+    pub modified: Option<String>,
+}
+
+/// Response from `fs_list_directory`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct ListDirectoryResponse {
+    pub path: String,
+    pub count: u64,
+    #[validate(len_eq = "count", nested)]
+    pub entries: Vec<DirectoryEntry>,
+}
+
+impl ListDirectoryResponse {
+    /// Entries that are files
+    pub fn files(&self) -> impl Iterator<Item = &DirectoryEntry> {
+        self.entries.iter().filter(|e| e.kind == EntryKind::File)
+    }
+
+    /// Entries that are directories
+    pub fn directories(&self) -> impl Iterator<Item = &DirectoryEntry> {
+        self.entries
+            .iter()
+            .filter(|e| e.kind == EntryKind::Directory)
+    }
+}
+
+/// Response from `fs_move_file`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct MoveFileResponse {
+    #[validate(non_empty)]
+    pub source: String,
+    #[validate(non_empty)]
+    pub destination: String,
+}
+
+/// Response from `fs_get_file_info`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct FileInfoResponse {
+    #[validate(non_empty)]
+    pub path: String,
+    pub kind: EntryKind,
+    pub size: u64,
+    #[serde(default)]
+    pub created: Option<String>,
+    #[serde(default)]
+    pub modified: Option<String>,
+    #[serde(default)]
+    pub accessed: Option<String>,
+    /// Unix permission bits in octal, e.g. `"644"`
+    #[serde(default)]
+    pub permissions: Option<String>,
+    #[serde(default)]
+    pub readonly: bool,
+    /// Number of lines, for text files
+    #[serde(default)]
+    pub line_count: Option<usize>,
+}
+
+/// A single file or content match from `fs_search`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct SearchMatch {
+    #[validate(non_empty)]
+    pub file: String,
+    /// Line of a content match (1-based)
+    #[serde(default)]
+    pub line: Option<u64>,
+    /// Matched text of a content match
+    #[serde(rename = "match", default)]
+    pub matched: Option<String>,
+}
+
+/// A page of results from a running `fs_search` session
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct SearchResultsResponse {
+    #[serde(alias = "sessionId")]
+    #[validate(non_empty)]
+    pub session_id: String,
+    /// Matches on this page
+    #[validate(nested)]
+    pub results: Vec<SearchMatch>,
+    /// Matches found so far, across all pages
+    pub total_results: u64,
+    /// Whether the search has finished
+    pub is_complete: bool,
+}
+
+/// Response from stopping an `fs_search` session
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct StopSearchResponse {
+    #[serde(alias = "sessionId")]
+    #[validate(non_empty)]
+    pub session_id: String,
+    /// Whether the search was still running and has been stopped
+    pub stopped: bool,
+}
@@ -0,0 +1,457 @@
+//! Typed responses for the local git tools
+//!
+//! Fields use the snake_case names of the Rust structs. Commit IDs, names and
+//! paths are checked to be non-empty, commit times to be positive, and list
+//! counts to match their arrays, so a malformed response fails validation
+//! instead of silently producing empty strings.
+
+use crate::validation::*;
+use serde::Deserialize;
+
+// ============================================================================
+// Status
+// ============================================================================
+
+/// Change recorded for a path in `git_status`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileStatus {
+    Added,
+    Modified,
+    Deleted,
+    Renamed,
+    Copied,
+    TypeChange,
+    Untracked,
+    Ignored,
+    Conflicted,
+    #[serde(other)]
+    Other,
+}
+
+/// A changed path in the working tree or index
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitStatusEntry {
+    #[validate(non_empty)]
+    pub path: String,
+    pub status: FileStatus,
+    /// Whether the change is in the index (staged) rather than the working tree
+    #[serde(default)]
+    pub staged: bool,
+    /// Previous path of a renamed or copied file
+    #[serde(default)]
+    pub original_path: Option<String>,
+}
+
+/// Response from `git_status`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitStatusResponse {
+    /// Current branch, or `None` with a detached HEAD
+    #[serde(default)]
+    pub branch: Option<String>,
+    #[serde(default)]
+    pub upstream: Option<String>,
+    /// Commits on the branch not yet on its upstream
+    #[serde(default)]
+    pub ahead: u64,
+    /// Commits on the upstream not yet on the branch
+    #[serde(default)]
+    pub behind: u64,
+    #[validate(nested)]
+    pub entries: Vec<GitStatusEntry>,
+}
+
+impl GitStatusResponse {
+    /// Whether there are no changes (untracked and ignored files included)
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Changes in the index
+    pub fn staged(&self) -> impl Iterator<Item = &GitStatusEntry> {
+        self.entries.iter().filter(|e| e.staged)
+    }
+
+    /// Changes in the working tree, including untracked files
+    pub fn unstaged(&self) -> impl Iterator<Item = &GitStatusEntry> {
+        self.entries.iter().filter(|e| !e.staged)
+    }
+
+    /// Paths with merge conflicts
+    pub fn conflicts(&self) -> impl Iterator<Item = &GitStatusEntry> {
+        self.entries
+            .iter()
+            .filter(|e| e.status == FileStatus::Conflicted)
+    }
+}
+
+// ============================================================================
+// Commits and history
+// ============================================================================
+
+/// Author or committer of a commit
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitSignature {
+    #[validate(non_empty)]
+    pub name: String,
+    pub email: String,
+    /// Unix timestamp in seconds
+    #[validate(range(min = 1))]
+    pub time: i64,
+}
+
+/// A commit as reported by `git_log`, `git_show` and `git_history`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitCommitInfo {
+    #[validate(non_empty)]
+    pub sha: String,
+    /// Full commit message
+    pub message: String,
+    /// First line of the message
+    #[serde(default)]
+    pub summary: Option<String>,
+    #[validate(nested)]
+    pub author: GitSignature,
+    #[serde(default)]
+    #[validate(nested)]
+    pub committer: Option<GitSignature>,
+    #[serde(default)]
+    pub parents: Vec<String>,
+}
+
+impl GitCommitInfo {
+    /// First line of the message
+    #[must_use]
+    pub fn summary(&self) -> &str {
+        self.summary
+            .as_deref()
+            .unwrap_or_else(|| self.message.lines().next().unwrap_or_default())
+    }
+
+    /// Whether the commit has more than one parent
+    #[must_use]
+    pub fn is_merge(&self) -> bool {
+        self.parents.len() > 1
+    }
+}
+
+/// Response from `git_log` and `git_history`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitLogResponse {
+    pub count: u64,
+    #[validate(len_eq = "count", nested)]
+    pub commits: Vec<GitCommitInfo>,
+}
+
+/// Response from `git_commit`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitCommitResponse {
+    #[validate(non_empty)]
+    pub sha: String,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default)]
+    pub files_changed: Option<u64>,
+}
+
+// ============================================================================
+// Diffs
+// ============================================================================
+
+/// Kind of a line in a diff hunk
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffLineKind {
+    Context,
+    Addition,
+    Deletion,
+}
+
+/// A line in a diff hunk
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitDiffLine {
+    pub kind: DiffLineKind,
+    pub content: String,
+    /// Line number in the old file (absent for additions)
+    #[serde(default)]
+    pub old_line: Option<u64>,
+    /// Line number in the new file (absent for deletions)
+    #[serde(default)]
+    pub new_line: Option<u64>,
+}
+
+/// A hunk of a file diff (`@@ -old_start,old_lines +new_start,new_lines @@`)
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitDiffHunk {
+    /// First line in the old file; 0 when the file was added
+    pub old_start: u64,
+    pub old_lines: u64,
+    /// First line in the new file; 0 when the file was deleted
+    pub new_start: u64,
+    pub new_lines: u64,
+    #[serde(default)]
+    pub header: String,
+    #[serde(default)]
+    pub lines: Vec<GitDiffLine>,
+}
+
+impl GitDiffHunk {
+    /// Added lines' content
+    pub fn additions(&self) -> impl Iterator<Item = &str> {
+        self.lines_of(DiffLineKind::Addition)
+    }
+
+    /// Deleted lines' content
+    pub fn deletions(&self) -> impl Iterator<Item = &str> {
+        self.lines_of(DiffLineKind::Deletion)
+    }
+
+    fn lines_of(&self, kind: DiffLineKind) -> impl Iterator<Item = &str> {
+        self.lines
+            .iter()
+            .filter(move |line| line.kind == kind)
+            .map(|line| line.content.as_str())
+    }
+}
+
+/// Changes to one file in a diff
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitDiffFile {
+    #[validate(non_empty)]
+    pub path: String,
+    /// Previous path of a renamed file
+    #[serde(default)]
+    pub old_path: Option<String>,
+    pub status: FileStatus,
+    #[serde(default)]
+    pub binary: bool,
+    #[serde(default)]
+    pub insertions: u64,
+    #[serde(default)]
+    pub deletions: u64,
+    #[serde(default)]
+    #[validate(nested)]
+    pub hunks: Vec<GitDiffHunk>,
+}
+
+/// Response from `git_diff`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitDiffResponse {
+    #[validate(nested)]
+    pub files: Vec<GitDiffFile>,
+    #[serde(default)]
+    pub insertions: u64,
+    #[serde(default)]
+    pub deletions: u64,
+}
+
+// ============================================================================
+// Branches, tags, remotes and stashes
+// ============================================================================
+
+/// A branch in `git_branch_list`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitBranchInfo {
+    #[validate(non_empty)]
+    pub name: String,
+    /// Whether this is the checked-out branch
+    #[serde(default)]
+    pub current: bool,
+    /// Whether this is a remote-tracking branch
+    #[serde(default)]
+    pub remote: bool,
+    #[serde(default)]
+    pub upstream: Option<String>,
+    /// Commit the branch points at
+    #[serde(default)]
+    pub commit: Option<String>,
+}
+
+/// Response from `git_branch_list`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitBranchListResponse {
+    pub count: u64,
+    #[validate(len_eq = "count", nested)]
+    pub branches: Vec<GitBranchInfo>,
+}
+
+impl GitBranchListResponse {
+    /// The checked-out branch, if HEAD is not detached
+    #[must_use]
+    pub fn current(&self) -> Option<&GitBranchInfo> {
+        self.branches.iter().find(|b| b.current)
+    }
+}
+
+/// Response from `git_branch_create`, `git_branch_delete` and `git_branch_rename`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitBranchResponse {
+    #[validate(non_empty)]
+    pub name: String,
+    /// Name before a rename
+    #[serde(default)]
+    pub previous_name: Option<String>,
+    /// Commit the branch points (or pointed) at
+    #[serde(default)]
+    pub commit: Option<String>,
+}
+
+/// A tag in `git_tag_list`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitTag {
+    #[validate(non_empty)]
+    pub name: String,
+    /// Commit the tag points at
+    #[validate(non_empty)]
+    pub target: String,
+    /// Message of an annotated tag
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// Response from `git_tag_list`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitTagListResponse {
+    pub count: u64,
+    #[validate(len_eq = "count", nested)]
+    pub tags: Vec<GitTag>,
+}
+
+/// A remote in `git_remote_list`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitRemote {
+    #[validate(non_empty)]
+    pub name: String,
+    #[validate(non_empty)]
+    pub url: String,
+    #[serde(default)]
+    pub push_url: Option<String>,
+}
+
+/// Response from `git_remote_list`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitRemoteListResponse {
+    #[validate(nested)]
+    pub remotes: Vec<GitRemote>,
+}
+
+/// An entry in `git_stash_list`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitStashEntry {
+    /// Position in the stash stack (`stash@{index}`)
+    pub index: u64,
+    pub message: String,
+    #[serde(default)]
+    pub branch: Option<String>,
+    #[serde(default)]
+    pub commit: Option<String>,
+}
+
+/// Response from `git_stash_list`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitStashListResponse {
+    #[validate(nested)]
+    pub stashes: Vec<GitStashEntry>,
+}
+
+// ============================================================================
+// Worktrees
+// ============================================================================
+
+/// A worktree in `git_worktree_list`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitWorktree {
+    #[validate(non_empty)]
+    pub path: String,
+    /// Checked-out branch, or `None` with a detached HEAD
+    #[serde(default)]
+    pub branch: Option<String>,
+    #[serde(default)]
+    pub head: Option<String>,
+    /// Whether this is the main worktree rather than a linked one
+    #[serde(default)]
+    pub main: bool,
+    #[serde(default)]
+    pub locked: bool,
+    #[serde(default)]
+    pub lock_reason: Option<String>,
+    #[serde(default)]
+    pub prunable: bool,
+}
+
+/// Response from `git_worktree_list`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitWorktreeListResponse {
+    pub count: u64,
+    #[validate(len_eq = "count", nested)]
+    pub worktrees: Vec<GitWorktree>,
+}
+
+/// Response from `git_worktree_add`, `git_worktree_remove`, `git_worktree_lock`
+/// and `git_worktree_unlock`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitWorktreeResponse {
+    #[validate(non_empty)]
+    pub path: String,
+    #[serde(default)]
+    pub branch: Option<String>,
+}
+
+// ============================================================================
+// Repository and synchronization
+// ============================================================================
+
+/// Response from `git_init`, `git_clone`, `git_open` and `git_discover`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitRepositoryResponse {
+    #[validate(non_empty)]
+    pub path: String,
+    #[serde(default)]
+    pub bare: bool,
+    /// Current branch, if any
+    #[serde(default)]
+    pub branch: Option<String>,
+}
+
+/// Response from `git_checkout`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitCheckoutResponse {
+    /// Branch, tag or commit that was checked out
+    #[validate(non_empty)]
+    pub reference: String,
+    #[serde(default)]
+    pub head: Option<String>,
+}
+
+/// Response from `git_merge`, `git_pull`, `git_rebase` and `git_cherry_pick`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitMergeResponse {
+    /// Whether the branch was fast-forwarded without a merge commit
+    #[serde(default)]
+    pub fast_forward: bool,
+    /// Resulting commit, unless the operation stopped on conflicts
+    #[serde(default)]
+    pub commit: Option<String>,
+    /// Paths left with conflicts
+    #[serde(default)]
+    pub conflicts: Vec<String>,
+}
+
+impl GitMergeResponse {
+    /// Whether the operation stopped with conflicts to resolve
+    #[must_use]
+    pub fn has_conflicts(&self) -> bool {
+        !self.conflicts.is_empty()
+    }
+}
+
+/// Response from `git_push` and `git_fetch`
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct GitSyncResponse {
+    #[validate(non_empty)]
+    pub remote: String,
+    /// Refs that were updated
+    #[serde(default)]
+    pub updated_refs: Vec<String>,
+}
@@ -0,0 +1,75 @@
+//! Typed responses for the follow-up actions of the `terminal` tool
+//!
+//! A command started with `start_terminal` is identified by its PID. Its
+//! output is read incrementally: each read starts at an offset and reports
+//! the offset the next read should start at, so nothing is returned twice.
+//! [`TerminalSession`](crate::TerminalSession) drives these calls.
+
+use crate::validation::*;
+use serde::Deserialize;
+
+/// Response from reading a command's output
+#[derive(Debug, Clone, Deserialize)]
+pub struct TerminalOutputResponse {
+    pub pid: i64,
+    /// Output produced since `offset`
+    pub output: String,
+    /// Offset this chunk starts at
+    #[serde(default)]
+    pub offset: u64,
+    /// Offset to pass to the next read
+    pub next_offset: u64,
+    /// Whether the command is still running
+    pub running: bool,
+    /// Exit code once the command has exited; `None` while running or if it
+    /// was killed by a signal
+    #[serde(default)]
+    pub exit_code: Option<i32>,
+}
+
+impl TerminalOutputResponse {
+    /// Whether the command has exited and this read reached the end of its output
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        !self.running && self.output.is_empty()
+    }
+}
+
+impl Validate for TerminalOutputResponse {
+    fn validate_into(&self, errors: &mut ValidationErrors) {
+        if self.pid < 1 {
+            errors.add("pid", below_minimum_error("pid", self.pid, 1));
+        }
+        if self.next_offset < self.offset {
+            errors.add(
+                "next_offset",
+                below_minimum_error("next_offset", self.next_offset, self.offset),
+            );
+        }
+        if self.running && self.exit_code.is_some() {
+            errors.add("exit_code", "exit_code must be absent while running");
+        }
+    }
+}
+
+/// Response from sending input to a command
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct SendInputResponse {
+    #[validate(range(min = 1))]
+    pub pid: i64,
+    /// Bytes written to the command's stdin
+    #[serde(default)]
+    pub bytes_written: usize,
+}
+
+/// Response from killing a command
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct KillTerminalResponse {
+    #[validate(range(min = 1))]
+    pub pid: i64,
+    /// Whether the command was still running and has been killed
+    pub killed: bool,
+    /// Exit code, if the command had already exited normally
+    #[serde(default)]
+    pub exit_code: Option<i32>,
+}
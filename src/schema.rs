@@ -0,0 +1,49 @@
+//! JSON Schema checks against the schemas tools publish in `tools/list`
+
+use crate::{ClientError, error::SchemaViolation};
+use jsonschema::Validator;
+use rmcp::model::JsonObject;
+
+/// Validate a tool's structured output against its `outputSchema`
+///
+/// # Errors
+///
+/// Returns `ClientError::OutputSchemaMismatch` listing every violation, or
+/// `ClientError::Protocol` if the schema itself cannot be compiled.
+pub(crate) fn validate_output(
+    tool_name: &str,
+    schema: &JsonObject,
+    output: &serde_json::Value,
+) -> Result<(), ClientError> {
+    let schema = serde_json::Value::Object(schema.clone());
+    let validator = jsonschema::validator_for(&schema).map_err(|e| {
+        ClientError::Protocol(format!(
+            "Tool '{}' has an invalid outputSchema: {}",
+            tool_name, e
+        ))
+    })?;
+
+    let violations = violations(&validator, output);
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(ClientError::OutputSchemaMismatch {
+            tool_name: tool_name.to_string(),
+            violations,
+        })
+    }
+}
+
+/// Every error `validator` reports for `instance`
+pub(crate) fn violations(
+    validator: &Validator,
+    instance: &serde_json::Value,
+) -> Vec<SchemaViolation> {
+    validator
+        .iter_errors(instance)
+        .map(|error| SchemaViolation {
+            instance_path: error.instance_path().as_str().to_string(),
+            message: error.to_string(),
+        })
+        .collect()
+}
@@ -0,0 +1,211 @@
+//! Paging through background `fs_search` sessions
+//!
+//! `fs_search` starts the search and returns a session ID right away; results
+//! are then fetched page by page while the search runs. [`SearchSession`]
+//! tracks how many results have been read, backs off while the search has
+//! nothing new, and stops the search on the server when it is dropped early.
+
+use crate::{
+    ClientError, KodegenClient,
+    polling::{Paged, spawn_cleanup, stream_items},
+    requests::{SearchResults, SearchResultsArgs, StopSearch, StopSearchArgs},
+    responses::{
+        StartSearchResponse,
+        filesystem::{SearchMatch, SearchResultsResponse, StopSearchResponse},
+    },
+};
+use std::time::Duration;
+use tokio_stream::Stream;
+
+/// Handle to a search started with `fs_search`
+///
+/// Created by [`KodegenClient::start_search`]. Pages continue where the
+/// previous one stopped, so [`next_page`](Self::next_page) and
+/// [`results`](Self::results) never return a match twice.
+///
+/// While the search is running but has no new results, polls back off
+/// exponentially (50ms doubling up to 1s by default; see
+/// [`backoff`](Self::backoff)).
+///
+/// Dropping the session before the search completes stops it on the server,
+/// from a background task on the current Tokio runtime.
+///
+/// # Example
+///
+/// ```ignore
+/// use kodegen_mcp_client::requests::{SearchType, StartSearchArgs};
+/// use tokio_stream::StreamExt;
+///
+/// let mut search = client
+///     .start_search(StartSearchArgs::new("/monorepo", "TODO", SearchType::Content))
+///     .await?;
+///
+/// let mut results = std::pin::pin!(search.results());
+/// while let Some(found) = results.next().await {
+///     let found = found?;
+///     println!("{}:{}", found.file, found.line.unwrap_or_default());
+/// }
+/// ```
+pub struct SearchSession {
+    client: KodegenClient,
+    session_id: String,
+    offset: u64,
+    total_results: u64,
+    complete: bool,
+    stopped: bool,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl SearchSession {
+    /// Attach to a search started with `fs_search`
+    ///
+    /// Results are read from the first one.
+    pub fn new(client: KodegenClient, started: &StartSearchResponse) -> Self {
+        Self {
+            client,
+            session_id: started.session_id.clone(),
+            offset: 0,
+            total_results: 0,
+            complete: false,
+            stopped: false,
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_secs(1),
+        }
+    }
+
+    /// Set the delay after the first poll without new results and the cap for later ones
+    ///
+    /// The delay doubles after every empty poll until it reaches `max`, and
+    /// starts over once results arrive.
+    #[must_use]
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// ID of the search session on the server
+    #[must_use]
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    /// Number of results read so far
+    #[must_use]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Number of results the server has found so far, as of the last poll
+    #[must_use]
+    pub fn total_results(&self) -> u64 {
+        self.total_results
+    }
+
+    /// Whether the search has finished and every result has been read
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Fetch the results found since the previous poll, without waiting
+    ///
+    /// The page may be empty while the search is still running.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `KodegenClient::invoke()`.
+    pub async fn poll(&mut self) -> Result<SearchResultsResponse, ClientError> {
+        let response = self
+            .client
+            .invoke::<SearchResults>(SearchResultsArgs::new(&self.session_id, self.offset))
+            .await?;
+        self.offset += response.results.len() as u64;
+        self.total_results = response.total_results;
+        // Servers may cap the results they return below `total_results`, so a
+        // finished search with nothing new is done too
+        self.complete = response.is_complete
+            && (self.offset >= response.total_results || response.results.is_empty());
+        Ok(response)
+    }
+
+    /// Wait for the next non-empty page of results
+    ///
+    /// Returns `None` once the search is complete or has been stopped.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `poll()`.
+    pub async fn next_page(&mut self) -> Result<Option<Vec<SearchMatch>>, ClientError> {
+        let mut delay = self.initial_backoff;
+        while !self.complete && !self.stopped {
+            let page = self.poll().await?;
+            if !page.results.is_empty() {
+                return Ok(Some(page.results));
+            }
+            if !self.complete {
+                tokio::time::sleep(delay).await;
+                delay = delay.saturating_mul(2).min(self.max_backoff);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Stream every remaining match as the search finds it
+    ///
+    /// Ends once the search is complete or has been stopped. The first error
+    /// ends the stream after being yielded.
+    pub fn results(&mut self) -> impl Stream<Item = Result<SearchMatch, ClientError>> + '_ {
+        stream_items(self)
+    }
+
+    /// Stop the search on the server
+    ///
+    /// Results already found can no longer be read through this session.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `KodegenClient::invoke()`.
+    pub async fn stop(&mut self) -> Result<StopSearchResponse, ClientError> {
+        let response = self
+            .client
+            .invoke::<StopSearch>(StopSearchArgs::new(&self.session_id))
+            .await?;
+        self.stopped = true;
+        Ok(response)
+    }
+}
+
+impl std::fmt::Debug for SearchSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SearchSession")
+            .field("session_id", &self.session_id)
+            .field("offset", &self.offset)
+            .field("total_results", &self.total_results)
+            .field("complete", &self.complete)
+            .field("stopped", &self.stopped)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Paged for SearchSession {
+    type Item = SearchMatch;
+
+    async fn next_items(&mut self) -> Result<Option<Vec<SearchMatch>>, ClientError> {
+        self.next_page().await
+    }
+}
+
+impl Drop for SearchSession {
+    fn drop(&mut self) {
+        if self.complete || self.stopped {
+            return;
+        }
+        let client = self.client.clone();
+        let args = StopSearchArgs::new(std::mem::take(&mut self.session_id));
+        spawn_cleanup(format!("search {}", args.session_id), async move {
+            client.invoke::<StopSearch>(args).await
+        });
+    }
+}
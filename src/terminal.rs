@@ -0,0 +1,231 @@
+//! Driving long-running terminal commands
+//!
+//! `start_terminal` returns as soon as the command has started (or its initial
+//! output timeout has passed); everything after that goes through the
+//! `terminal` tool, addressed by PID. [`TerminalSession`] keeps track of the
+//! PID and the output offset so callers can read, stream, wait and kill
+//! without repeating the polling loop.
+
+use crate::{
+    ClientError, KodegenClient,
+    requests::{
+        KillTerminal, KillTerminalArgs, ReadTerminalOutput, ReadTerminalOutputArgs,
+        SendTerminalInput, SendTerminalInputArgs,
+    },
+    responses::{
+        StartTerminalCommandResponse,
+        terminal::{KillTerminalResponse, SendInputResponse, TerminalOutputResponse},
+    },
+};
+use std::time::Duration;
+use tokio_stream::Stream;
+
+/// Delay between reads that return no new output
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Handle to a command started with `start_terminal`
+///
+/// Created by [`KodegenClient::start_terminal_command`]. Output is read
+/// incrementally: every read continues where the previous one stopped, so
+/// [`read_output`](Self::read_output), [`output_stream`](Self::output_stream)
+/// and [`wait`](Self::wait) never return the same output twice.
+///
+/// Dropping the session does not stop the command; call [`kill`](Self::kill).
+///
+/// # Example
+///
+/// ```ignore
+/// use kodegen_mcp_client::requests::StartTerminalCommandArgs;
+/// use tokio_stream::StreamExt;
+///
+/// let mut build = client
+///     .start_terminal_command(StartTerminalCommandArgs::new("cargo build --release"))
+///     .await?;
+///
+/// let mut output = std::pin::pin!(build.output_stream());
+/// while let Some(chunk) = output.next().await {
+///     print!("{}", chunk?);
+/// }
+/// drop(output);
+///
+/// match build.exit_code() {
+///     Some(0) => println!("Build succeeded"),
+///     code => println!("Build failed: {:?}", code),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TerminalSession {
+    client: KodegenClient,
+    pid: i64,
+    offset: u64,
+    finished: bool,
+    exit_code: Option<i32>,
+    poll_interval: Duration,
+}
+
+impl std::fmt::Debug for TerminalSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TerminalSession")
+            .field("pid", &self.pid)
+            .field("offset", &self.offset)
+            .field("finished", &self.finished)
+            .field("exit_code", &self.exit_code)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TerminalSession {
+    /// Attach to a command started with `start_terminal`
+    ///
+    /// Output is read from the beginning.
+    pub fn new(client: KodegenClient, started: &StartTerminalCommandResponse) -> Self {
+        Self {
+            client,
+            pid: started.pid,
+            offset: 0,
+            finished: false,
+            exit_code: None,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// Set the delay between reads that return no new output (default 250ms)
+    #[must_use]
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Process ID of the command
+    #[must_use]
+    pub fn pid(&self) -> i64 {
+        self.pid
+    }
+
+    /// Offset the next read starts at
+    #[must_use]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Whether the command has exited and all of its output has been read
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Exit code, once a read has observed the command exiting
+    ///
+    /// `None` while running, or if the command was killed by a signal.
+    #[must_use]
+    pub fn exit_code(&self) -> Option<i32> {
+        self.exit_code
+    }
+
+    /// Read the output produced since the previous read and advance the offset
+    ///
+    /// Returns immediately, with empty `output` if nothing new was produced.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `KodegenClient::invoke()`.
+    pub async fn read_output(&mut self) -> Result<TerminalOutputResponse, ClientError> {
+        let response = self.read_output_at(self.offset).await?;
+        self.offset = response.next_offset;
+        if !response.running {
+            self.exit_code = response.exit_code;
+            self.finished = response.output.is_empty();
+        }
+        Ok(response)
+    }
+
+    /// Read output from `offset` without moving the session's offset
+    ///
+    /// Use it to re-read output, e.g. from 0 to get everything so far.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `KodegenClient::invoke()`.
+    pub async fn read_output_at(&self, offset: u64) -> Result<TerminalOutputResponse, ClientError> {
+        self.client
+            .invoke::<ReadTerminalOutput>(ReadTerminalOutputArgs::new(self.pid, offset))
+            .await
+    }
+
+    /// Write `input` to the command's stdin
+    ///
+    /// Input is sent as is; end it with `\n` to submit a line.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `KodegenClient::invoke()`.
+    pub async fn send_input(
+        &self,
+        input: impl Into<String>,
+    ) -> Result<SendInputResponse, ClientError> {
+        self.client
+            .invoke::<SendTerminalInput>(SendTerminalInputArgs::new(self.pid, input))
+            .await
+    }
+
+    /// Wait for the command to exit and return its exit code
+    ///
+    /// Polls until the command has exited and its remaining output has been
+    /// read. The output read while waiting is discarded; use
+    /// [`output_stream`](Self::output_stream) to keep it. Wrap the call in
+    /// `tokio::time::timeout` to bound the wait.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `read_output()`.
+    pub async fn wait(&mut self) -> Result<Option<i32>, ClientError> {
+        while !self.finished {
+            let response = self.read_output().await?;
+            if response.output.is_empty() && !self.finished {
+                tokio::time::sleep(self.poll_interval).await;
+            }
+        }
+        Ok(self.exit_code)
+    }
+
+    /// Kill the command
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `KodegenClient::invoke()`.
+    pub async fn kill(&mut self) -> Result<KillTerminalResponse, ClientError> {
+        let response = self
+            .client
+            .invoke::<KillTerminal>(KillTerminalArgs::new(self.pid))
+            .await?;
+        if !response.killed {
+            self.exit_code = response.exit_code;
+        }
+        Ok(response)
+    }
+
+    /// Stream the command's output as it is produced
+    ///
+    /// Yields each non-empty chunk and ends once the command has exited and
+    /// all output has been read (check [`exit_code`](Self::exit_code)
+    /// afterwards). Reads that return nothing are retried after the poll
+    /// interval. The first error ends the stream after being yielded.
+    pub fn output_stream(&mut self) -> impl Stream<Item = Result<String, ClientError>> + '_ {
+        futures::stream::unfold((self, false), |(session, failed)| async move {
+            if failed {
+                return None;
+            }
+            while !session.finished {
+                match session.read_output().await {
+                    Ok(response) if !response.output.is_empty() => {
+                        return Some((Ok(response.output), (session, false)));
+                    }
+                    Ok(_) if session.finished => break,
+                    Ok(_) => tokio::time::sleep(session.poll_interval).await,
+                    Err(e) => return Some((Err(e), (session, true))),
+                }
+            }
+            None
+        })
+    }
+}
@@ -0,0 +1,496 @@
+//! Names of the kodegen tools, grouped by category
+//!
+//! Each category submodule holds one constant per tool, plus an `ALL` slice.
+//! Values come from `kodegen_config`, the single source of truth shared with
+//! the servers, so call sites never need to hard-code tool name literals:
+//!
+//! ```ignore
+//! use kodegen_mcp_client::tools::{self, ToolCategory};
+//!
+//! let result = client.call_tool(tools::filesystem::READ_FILE, args).await?;
+//! assert_eq!(tools::category_of("fs_read_file"), Some(ToolCategory::Filesystem));
+//! ```
+
+use std::fmt;
+
+/// Category a kodegen tool belongs to
+///
+/// Matches the category names in `kodegen_config` used for tool metadata and
+/// routing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToolCategory {
+    /// Browser automation and web interaction
+    Browser,
+    /// Web crawling, scraping and search
+    Citescrape,
+    /// Claude sub-agent delegation
+    ClaudeAgent,
+    /// Configuration value management
+    Config,
+    /// Database operations and schema inspection
+    Database,
+    /// File system operations
+    Filesystem,
+    /// Git version control operations
+    Git,
+    /// GitHub API operations
+    GitHub,
+    /// Tool usage statistics and introspection
+    Introspection,
+    /// Memory and knowledge management (served by candle-agent)
+    Memory,
+    /// Process management
+    Process,
+    /// Prompt template management
+    Prompt,
+    /// Reasoner tool backend
+    Reasoner,
+    /// Sequential thinking
+    SequentialThinking,
+    /// Terminal command execution
+    Terminal,
+}
+
+impl ToolCategory {
+    /// Every category
+    pub const ALL: &[ToolCategory] = &[
+        ToolCategory::Browser,
+        ToolCategory::Citescrape,
+        ToolCategory::ClaudeAgent,
+        ToolCategory::Config,
+        ToolCategory::Database,
+        ToolCategory::Filesystem,
+        ToolCategory::Git,
+        ToolCategory::GitHub,
+        ToolCategory::Introspection,
+        ToolCategory::Memory,
+        ToolCategory::Process,
+        ToolCategory::Prompt,
+        ToolCategory::Reasoner,
+        ToolCategory::SequentialThinking,
+        ToolCategory::Terminal,
+    ];
+
+    /// The category name used in tool metadata, e.g. `"filesystem"`
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ToolCategory::Browser => kodegen_config::CATEGORY_BROWSER.name,
+            ToolCategory::Citescrape => kodegen_config::CATEGORY_CITESCRAPE.name,
+            ToolCategory::ClaudeAgent => kodegen_config::CATEGORY_CLAUDE_AGENT.name,
+            ToolCategory::Config => kodegen_config::CATEGORY_CONFIG.name,
+            ToolCategory::Database => kodegen_config::CATEGORY_DATABASE.name,
+            ToolCategory::Filesystem => kodegen_config::CATEGORY_FILESYSTEM.name,
+            ToolCategory::Git => kodegen_config::CATEGORY_GIT.name,
+            ToolCategory::GitHub => kodegen_config::CATEGORY_GITHUB.name,
+            ToolCategory::Introspection => kodegen_config::CATEGORY_INTROSPECTION.name,
+            ToolCategory::Memory => kodegen_config::CATEGORY_MEMORY.name,
+            ToolCategory::Process => kodegen_config::CATEGORY_PROCESS.name,
+            ToolCategory::Prompt => kodegen_config::CATEGORY_PROMPT.name,
+            ToolCategory::Reasoner => kodegen_config::CATEGORY_REASONER.name,
+            ToolCategory::SequentialThinking => kodegen_config::CATEGORY_SEQUENTIAL_THINKING.name,
+            ToolCategory::Terminal => kodegen_config::CATEGORY_TERMINAL.name,
+        }
+    }
+
+    /// Names of every tool in this category
+    #[must_use]
+    pub fn tools(self) -> &'static [&'static str] {
+        match self {
+            ToolCategory::Browser => browser::ALL,
+            ToolCategory::Citescrape => citescrape::ALL,
+            ToolCategory::ClaudeAgent => claude_agent::ALL,
+            ToolCategory::Config => config::ALL,
+            ToolCategory::Database => database::ALL,
+            ToolCategory::Filesystem => filesystem::ALL,
+            ToolCategory::Git => git::ALL,
+            ToolCategory::GitHub => github::ALL,
+            ToolCategory::Introspection => introspection::ALL,
+            ToolCategory::Memory => memory::ALL,
+            ToolCategory::Process => process::ALL,
+            ToolCategory::Prompt => prompt::ALL,
+            ToolCategory::Reasoner => reasoner::ALL,
+            ToolCategory::SequentialThinking => sequential_thinking::ALL,
+            ToolCategory::Terminal => terminal::ALL,
+        }
+    }
+}
+
+impl fmt::Display for ToolCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The category of the tool called `name`, or `None` if it is not a kodegen tool
+#[must_use]
+pub fn category_of(name: &str) -> Option<ToolCategory> {
+    ToolCategory::ALL
+        .iter()
+        .copied()
+        .find(|category| category.tools().contains(&name))
+}
+
+/// Browser automation and web interaction
+pub mod browser {
+    pub const AGENT: &str = kodegen_config::BROWSER_AGENT;
+    pub const AGENT_KILL: &str = kodegen_config::BROWSER_AGENT_KILL;
+    pub const CLICK: &str = kodegen_config::BROWSER_CLICK;
+    pub const EVAL: &str = kodegen_config::BROWSER_EVAL;
+    pub const EXTRACT_TEXT: &str = kodegen_config::BROWSER_EXTRACT_TEXT;
+    pub const NAVIGATE: &str = kodegen_config::BROWSER_NAVIGATE;
+    pub const RESEARCH: &str = kodegen_config::BROWSER_RESEARCH;
+    pub const SCREENSHOT: &str = kodegen_config::BROWSER_SCREENSHOT;
+    pub const SCROLL: &str = kodegen_config::BROWSER_SCROLL;
+    pub const TYPE_TEXT: &str = kodegen_config::BROWSER_TYPE_TEXT;
+
+    /// Every tool in this category
+    pub const ALL: &[&str] = &[
+        AGENT,
+        AGENT_KILL,
+        CLICK,
+        EVAL,
+        EXTRACT_TEXT,
+        NAVIGATE,
+        RESEARCH,
+        SCREENSHOT,
+        SCROLL,
+        TYPE_TEXT,
+    ];
+}
+
+/// Web crawling, scraping and search
+pub mod citescrape {
+    pub const FETCH: &str = kodegen_config::FETCH;
+    pub const SCRAPE_URL: &str = kodegen_config::SCRAPE_URL;
+    pub const WEB_SEARCH: &str = kodegen_config::WEB_SEARCH;
+
+    /// Every tool in this category
+    pub const ALL: &[&str] = &[FETCH, SCRAPE_URL, WEB_SEARCH];
+}
+
+/// Claude sub-agent delegation
+pub mod claude_agent {
+    pub const CLAUDE_AGENT: &str = kodegen_config::CLAUDE_AGENT;
+
+    /// Every tool in this category
+    pub const ALL: &[&str] = &[CLAUDE_AGENT];
+}
+
+/// Configuration value management
+pub mod config {
+    pub const GET: &str = kodegen_config::CONFIG_GET;
+    pub const SET: &str = kodegen_config::CONFIG_SET;
+
+    /// Every tool in this category
+    pub const ALL: &[&str] = &[GET, SET];
+}
+
+/// Database operations and schema inspection
+pub mod database {
+    pub const EXECUTE_SQL: &str = kodegen_config::DB_EXECUTE_SQL;
+    pub const LIST_SCHEMAS: &str = kodegen_config::DB_LIST_SCHEMAS;
+    pub const LIST_TABLES: &str = kodegen_config::DB_LIST_TABLES;
+    pub const POOL_STATS: &str = kodegen_config::DB_POOL_STATS;
+    pub const STORED_PROCEDURES: &str = kodegen_config::DB_STORED_PROCEDURES;
+    pub const TABLE_INDEXES: &str = kodegen_config::DB_TABLE_INDEXES;
+    pub const TABLE_SCHEMA: &str = kodegen_config::DB_TABLE_SCHEMA;
+
+    /// Every tool in this category
+    pub const ALL: &[&str] = &[
+        EXECUTE_SQL,
+        LIST_SCHEMAS,
+        LIST_TABLES,
+        POOL_STATS,
+        STORED_PROCEDURES,
+        TABLE_INDEXES,
+        TABLE_SCHEMA,
+    ];
+}
+
+/// File system operations
+pub mod filesystem {
+    pub const CREATE_DIRECTORY: &str = kodegen_config::FS_CREATE_DIRECTORY;
+    pub const DELETE_DIRECTORY: &str = kodegen_config::FS_DELETE_DIRECTORY;
+    pub const DELETE_FILE: &str = kodegen_config::FS_DELETE_FILE;
+    pub const EDIT_BLOCK: &str = kodegen_config::FS_EDIT_BLOCK;
+    pub const GET_FILE_INFO: &str = kodegen_config::FS_GET_FILE_INFO;
+    pub const LIST_DIRECTORY: &str = kodegen_config::FS_LIST_DIRECTORY;
+    pub const MOVE_FILE: &str = kodegen_config::FS_MOVE_FILE;
+    pub const READ_FILE: &str = kodegen_config::FS_READ_FILE;
+    pub const READ_MULTIPLE_FILES: &str = kodegen_config::FS_READ_MULTIPLE_FILES;
+    pub const SEARCH: &str = kodegen_config::FS_SEARCH;
+    pub const WRITE_FILE: &str = kodegen_config::FS_WRITE_FILE;
+
+    /// Every tool in this category
+    pub const ALL: &[&str] = &[
+        CREATE_DIRECTORY,
+        DELETE_DIRECTORY,
+        DELETE_FILE,
+        EDIT_BLOCK,
+        GET_FILE_INFO,
+        LIST_DIRECTORY,
+        MOVE_FILE,
+        READ_FILE,
+        READ_MULTIPLE_FILES,
+        SEARCH,
+        WRITE_FILE,
+    ];
+}
+
+/// Git version control operations
+pub mod git {
+    pub const ADD: &str = kodegen_config::GIT_ADD;
+    pub const BRANCH_CREATE: &str = kodegen_config::GIT_BRANCH_CREATE;
+    pub const BRANCH_DELETE: &str = kodegen_config::GIT_BRANCH_DELETE;
+    pub const BRANCH_LIST: &str = kodegen_config::GIT_BRANCH_LIST;
+    pub const BRANCH_RENAME: &str = kodegen_config::GIT_BRANCH_RENAME;
+    pub const CHECKOUT: &str = kodegen_config::GIT_CHECKOUT;
+    pub const CHERRY_PICK: &str = kodegen_config::GIT_CHERRY_PICK;
+    pub const CLONE: &str = kodegen_config::GIT_CLONE;
+    pub const COMMIT: &str = kodegen_config::GIT_COMMIT;
+    pub const CONFIG_GET: &str = kodegen_config::GIT_CONFIG_GET;
+    pub const CONFIG_SET: &str = kodegen_config::GIT_CONFIG_SET;
+    pub const DIFF: &str = kodegen_config::GIT_DIFF;
+    pub const DISCOVER: &str = kodegen_config::GIT_DISCOVER;
+    pub const FETCH: &str = kodegen_config::GIT_FETCH;
+    pub const HISTORY: &str = kodegen_config::GIT_HISTORY;
+    pub const INIT: &str = kodegen_config::GIT_INIT;
+    pub const LOG: &str = kodegen_config::GIT_LOG;
+    pub const MERGE: &str = kodegen_config::GIT_MERGE;
+    pub const OPEN: &str = kodegen_config::GIT_OPEN;
+    pub const PULL: &str = kodegen_config::GIT_PULL;
+    pub const PUSH: &str = kodegen_config::GIT_PUSH;
+    pub const REBASE: &str = kodegen_config::GIT_REBASE;
+    pub const REMOTE_ADD: &str = kodegen_config::GIT_REMOTE_ADD;
+    pub const REMOTE_LIST: &str = kodegen_config::GIT_REMOTE_LIST;
+    pub const REMOTE_REMOVE: &str = kodegen_config::GIT_REMOTE_REMOVE;
+    pub const RESET: &str = kodegen_config::GIT_RESET;
+    pub const REVERT: &str = kodegen_config::GIT_REVERT;
+    pub const SHOW: &str = kodegen_config::GIT_SHOW;
+    pub const STASH: &str = kodegen_config::GIT_STASH;
+    pub const STASH_APPLY: &str = kodegen_config::GIT_STASH_APPLY;
+    pub const STASH_LIST: &str = kodegen_config::GIT_STASH_LIST;
+    pub const STASH_POP: &str = kodegen_config::GIT_STASH_POP;
+    pub const STATUS: &str = kodegen_config::GIT_STATUS;
+    pub const TAG: &str = kodegen_config::GIT_TAG;
+    pub const TAG_CREATE: &str = kodegen_config::GIT_TAG_CREATE;
+    pub const TAG_LIST: &str = kodegen_config::GIT_TAG_LIST;
+    pub const WORKTREE_ADD: &str = kodegen_config::GIT_WORKTREE_ADD;
+    pub const WORKTREE_LIST: &str = kodegen_config::GIT_WORKTREE_LIST;
+    pub const WORKTREE_LOCK: &str = kodegen_config::GIT_WORKTREE_LOCK;
+    pub const WORKTREE_PRUNE: &str = kodegen_config::GIT_WORKTREE_PRUNE;
+    pub const WORKTREE_REMOVE: &str = kodegen_config::GIT_WORKTREE_REMOVE;
+    pub const WORKTREE_UNLOCK: &str = kodegen_config::GIT_WORKTREE_UNLOCK;
+
+    /// Every tool in this category
+    pub const ALL: &[&str] = &[
+        ADD,
+        BRANCH_CREATE,
+        BRANCH_DELETE,
+        BRANCH_LIST,
+        BRANCH_RENAME,
+        CHECKOUT,
+        CHERRY_PICK,
+        CLONE,
+        COMMIT,
+        CONFIG_GET,
+        CONFIG_SET,
+        DIFF,
+        DISCOVER,
+        FETCH,
+        HISTORY,
+        INIT,
+        LOG,
+        MERGE,
+        OPEN,
+        PULL,
+        PUSH,
+        REBASE,
+        REMOTE_ADD,
+        REMOTE_LIST,
+        REMOTE_REMOVE,
+        RESET,
+        REVERT,
+        SHOW,
+        STASH,
+        STASH_APPLY,
+        STASH_LIST,
+        STASH_POP,
+        STATUS,
+        TAG,
+        TAG_CREATE,
+        TAG_LIST,
+        WORKTREE_ADD,
+        WORKTREE_LIST,
+        WORKTREE_LOCK,
+        WORKTREE_PRUNE,
+        WORKTREE_REMOVE,
+        WORKTREE_UNLOCK,
+    ];
+}
+
+/// GitHub API operations
+pub mod github {
+    pub const ACCEPT_REPO_INVITATION: &str = kodegen_config::GITHUB_ACCEPT_REPO_INVITATION;
+    pub const ADD_ISSUE_COMMENT: &str = kodegen_config::GITHUB_ADD_ISSUE_COMMENT;
+    pub const ADD_PULL_REQUEST_REVIEW_COMMENT: &str =
+        kodegen_config::GITHUB_ADD_PULL_REQUEST_REVIEW_COMMENT;
+    pub const CODE_SCANNING_ALERTS: &str = kodegen_config::GITHUB_CODE_SCANNING_ALERTS;
+    pub const CREATE_BRANCH: &str = kodegen_config::GITHUB_CREATE_BRANCH;
+    pub const CREATE_ISSUE: &str = kodegen_config::GITHUB_CREATE_ISSUE;
+    pub const CREATE_OR_UPDATE_FILE: &str = kodegen_config::GITHUB_CREATE_OR_UPDATE_FILE;
+    pub const CREATE_PULL_REQUEST: &str = kodegen_config::GITHUB_CREATE_PULL_REQUEST;
+    pub const CREATE_PULL_REQUEST_REVIEW: &str = kodegen_config::GITHUB_CREATE_PULL_REQUEST_REVIEW;
+    pub const CREATE_RELEASE: &str = kodegen_config::GITHUB_CREATE_RELEASE;
+    pub const CREATE_REPOSITORY: &str = kodegen_config::GITHUB_CREATE_REPOSITORY;
+    pub const DELETE_BRANCH: &str = kodegen_config::GITHUB_DELETE_BRANCH;
+    pub const DELETE_FILE: &str = kodegen_config::GITHUB_DELETE_FILE;
+    pub const FORK_REPOSITORY: &str = kodegen_config::GITHUB_FORK_REPOSITORY;
+    pub const GET_COMMIT: &str = kodegen_config::GITHUB_GET_COMMIT;
+    pub const GET_FILE_CONTENTS: &str = kodegen_config::GITHUB_GET_FILE_CONTENTS;
+    pub const GET_ISSUE: &str = kodegen_config::GITHUB_GET_ISSUE;
+    pub const GET_ISSUE_COMMENTS: &str = kodegen_config::GITHUB_GET_ISSUE_COMMENTS;
+    pub const GET_ME: &str = kodegen_config::GITHUB_GET_ME;
+    pub const GET_PULL_REQUEST_FILES: &str = kodegen_config::GITHUB_GET_PULL_REQUEST_FILES;
+    pub const GET_PULL_REQUEST_REVIEWS: &str = kodegen_config::GITHUB_GET_PULL_REQUEST_REVIEWS;
+    pub const GET_PULL_REQUEST_STATUS: &str = kodegen_config::GITHUB_GET_PULL_REQUEST_STATUS;
+    pub const LIST_BRANCHES: &str = kodegen_config::GITHUB_LIST_BRANCHES;
+    pub const LIST_COMMITS: &str = kodegen_config::GITHUB_LIST_COMMITS;
+    pub const LIST_ISSUES: &str = kodegen_config::GITHUB_LIST_ISSUES;
+    pub const LIST_PULL_REQUESTS: &str = kodegen_config::GITHUB_LIST_PULL_REQUESTS;
+    pub const LIST_REPOS: &str = kodegen_config::GITHUB_LIST_REPOS;
+    pub const MERGE_PULL_REQUEST: &str = kodegen_config::GITHUB_MERGE_PULL_REQUEST;
+    pub const PENDING_INVITATIONS: &str = kodegen_config::GITHUB_PENDING_INVITATIONS;
+    pub const PUSH_FILE: &str = kodegen_config::GITHUB_PUSH_FILE;
+    pub const PUSH_FILES: &str = kodegen_config::GITHUB_PUSH_FILES;
+    pub const REQUEST_COPILOT_REVIEW: &str = kodegen_config::GITHUB_REQUEST_COPILOT_REVIEW;
+    pub const SEARCH_CODE: &str = kodegen_config::GITHUB_SEARCH_CODE;
+    pub const SEARCH_ISSUES: &str = kodegen_config::GITHUB_SEARCH_ISSUES;
+    pub const SEARCH_REPOSITORIES: &str = kodegen_config::GITHUB_SEARCH_REPOSITORIES;
+    pub const SEARCH_USERS: &str = kodegen_config::GITHUB_SEARCH_USERS;
+    pub const SECRET_SCANNING_ALERTS: &str = kodegen_config::GITHUB_SECRET_SCANNING_ALERTS;
+    pub const UPDATE_ISSUE: &str = kodegen_config::GITHUB_UPDATE_ISSUE;
+    pub const UPDATE_PULL_REQUEST: &str = kodegen_config::GITHUB_UPDATE_PULL_REQUEST;
+
+    /// Every tool in this category
+    pub const ALL: &[&str] = &[
+        ACCEPT_REPO_INVITATION,
+        ADD_ISSUE_COMMENT,
+        ADD_PULL_REQUEST_REVIEW_COMMENT,
+        CODE_SCANNING_ALERTS,
+        CREATE_BRANCH,
+        CREATE_ISSUE,
+        CREATE_OR_UPDATE_FILE,
+        CREATE_PULL_REQUEST,
+        CREATE_PULL_REQUEST_REVIEW,
+        CREATE_RELEASE,
+        CREATE_REPOSITORY,
+        DELETE_BRANCH,
+        DELETE_FILE,
+        FORK_REPOSITORY,
+        GET_COMMIT,
+        GET_FILE_CONTENTS,
+        GET_ISSUE,
+        GET_ISSUE_COMMENTS,
+        GET_ME,
+        GET_PULL_REQUEST_FILES,
+        GET_PULL_REQUEST_REVIEWS,
+        GET_PULL_REQUEST_STATUS,
+        LIST_BRANCHES,
+        LIST_COMMITS,
+        LIST_ISSUES,
+        LIST_PULL_REQUESTS,
+        LIST_REPOS,
+        MERGE_PULL_REQUEST,
+        PENDING_INVITATIONS,
+        PUSH_FILE,
+        PUSH_FILES,
+        REQUEST_COPILOT_REVIEW,
+        SEARCH_CODE,
+        SEARCH_ISSUES,
+        SEARCH_REPOSITORIES,
+        SEARCH_USERS,
+        SECRET_SCANNING_ALERTS,
+        UPDATE_ISSUE,
+        UPDATE_PULL_REQUEST,
+    ];
+}
+
+/// Tool usage statistics and introspection
+pub mod introspection {
+    pub const GET_EVENTS: &str = kodegen_config::INTROSPECTION_GET_EVENTS;
+    pub const INSPECT_TOOL_CALLS: &str = kodegen_config::INTROSPECTION_INSPECT_TOOL_CALLS;
+    pub const INSPECT_USAGE_STATS: &str = kodegen_config::INTROSPECTION_INSPECT_USAGE_STATS;
+    pub const LIST_TOOLS: &str = kodegen_config::INTROSPECTION_LIST_TOOLS;
+    /// Unprefixed name of `INSPECT_TOOL_CALLS` still registered by servers
+    pub const LEGACY_INSPECT_TOOL_CALLS: &str = kodegen_config::INSPECT_TOOL_CALLS;
+    /// Unprefixed name of `INSPECT_USAGE_STATS` still registered by servers
+    pub const LEGACY_INSPECT_USAGE_STATS: &str = kodegen_config::INSPECT_USAGE_STATS;
+
+    /// Every tool in this category
+    pub const ALL: &[&str] = &[
+        GET_EVENTS,
+        INSPECT_TOOL_CALLS,
+        INSPECT_USAGE_STATS,
+        LIST_TOOLS,
+        LEGACY_INSPECT_TOOL_CALLS,
+        LEGACY_INSPECT_USAGE_STATS,
+    ];
+}
+
+/// Memory and knowledge management (served by candle-agent)
+pub mod memory {
+    pub const CHECK_MEMORIZE_STATUS: &str = kodegen_config::MEMORY_CHECK_MEMORIZE_STATUS;
+    pub const LIST_LIBRARIES: &str = kodegen_config::MEMORY_LIST_LIBRARIES;
+    pub const MEMORIZE: &str = kodegen_config::MEMORY_MEMORIZE;
+    pub const RECALL: &str = kodegen_config::MEMORY_RECALL;
+
+    /// Every tool in this category
+    pub const ALL: &[&str] = &[CHECK_MEMORIZE_STATUS, LIST_LIBRARIES, MEMORIZE, RECALL];
+}
+
+/// Process management
+pub mod process {
+    pub const KILL: &str = kodegen_config::PROCESS_KILL;
+    pub const LIST: &str = kodegen_config::PROCESS_LIST;
+
+    /// Every tool in this category
+    pub const ALL: &[&str] = &[KILL, LIST];
+}
+
+/// Prompt template management
+pub mod prompt {
+    pub const ADD: &str = kodegen_config::PROMPT_ADD;
+    pub const DELETE: &str = kodegen_config::PROMPT_DELETE;
+    pub const EDIT: &str = kodegen_config::PROMPT_EDIT;
+    pub const GET: &str = kodegen_config::PROMPT_GET;
+
+    /// Every tool in this category
+    pub const ALL: &[&str] = &[ADD, DELETE, EDIT, GET];
+}
+
+/// Reasoner tool backend
+pub mod reasoner {
+    pub const REASONER: &str = kodegen_config::REASONER;
+
+    /// Every tool in this category
+    pub const ALL: &[&str] = &[REASONER];
+}
+
+/// Sequential thinking
+pub mod sequential_thinking {
+    pub const SEQUENTIAL_THINKING: &str = kodegen_config::SEQUENTIAL_THINKING;
+
+    /// Every tool in this category
+    pub const ALL: &[&str] = &[SEQUENTIAL_THINKING];
+}
+
+/// Terminal command execution
+pub mod terminal {
+    pub const START_TERMINAL: &str = kodegen_config::START_TERMINAL;
+    pub const TERMINAL: &str = kodegen_config::TERMINAL;
+
+    /// Every tool in this category
+    pub const ALL: &[&str] = &[START_TERMINAL, TERMINAL];
+}
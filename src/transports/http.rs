@@ -1,14 +1,341 @@
 // packages/mcp-client/src/transports/http.rs
 use super::create_client_info;
-use crate::{ClientError, KodegenClient, KodegenConnection};
-use reqwest::header::HeaderMap;
+use crate::{
+    ClientError, KodegenClient, KodegenConnection,
+    handler::{
+        ClientHandlers, ElicitationHandler, KodegenClientHandler, RootsProvider, SamplingHandler,
+    },
+    options::CallHeaders,
+};
+use futures::{StreamExt, stream::BoxStream};
+use reqwest::header::{
+    ACCEPT, CONTENT_TYPE, HeaderMap, HeaderValue, IntoHeaderName, WWW_AUTHENTICATE,
+};
 use rmcp::{
     ServiceExt,
+    model::{ClientJsonRpcMessage, GetExtensions, JsonRpcMessage, ServerJsonRpcMessage},
     transport::{
         StreamableHttpClientTransport,
-        streamable_http_client::StreamableHttpClientTransportConfig,
+        common::http_header::{EVENT_STREAM_MIME_TYPE, HEADER_SESSION_ID, JSON_MIME_TYPE},
+        streamable_http_client::{
+            AuthRequiredError, SseError, StreamableHttpClient, StreamableHttpClientTransportConfig,
+            StreamableHttpError, StreamableHttpPostResponse,
+        },
     },
 };
+use sse_stream::{Sse, SseStream};
+use std::{sync::Arc, time::Duration};
+
+/// reqwest client that adds per-call headers set through `CallOptions`
+///
+/// Messages without per-call headers are posted by rmcp's implementation for
+/// `reqwest::Client`. Requests carrying [`CallHeaders`] are posted by
+/// [`post_with_headers`](Self::post_with_headers) on the same client, so
+/// pooled connections and TLS sessions are reused; the per-call headers
+/// override the connection's default headers of the same name.
+#[derive(Clone)]
+struct PerCallHeadersClient {
+    client: reqwest::Client,
+}
+
+impl PerCallHeadersClient {
+    /// Post `message` with `headers` added to the HTTP request
+    ///
+    /// Mirrors rmcp's `post_message` for `reqwest::Client`, which builds the
+    /// request internally and offers no way to add headers to it.
+    async fn post_with_headers(
+        &self,
+        uri: Arc<str>,
+        message: ClientJsonRpcMessage,
+        session_id: Option<Arc<str>>,
+        auth_header: Option<String>,
+        headers: HeaderMap,
+    ) -> Result<StreamableHttpPostResponse, StreamableHttpError<reqwest::Error>> {
+        let mut request = self
+            .client
+            .post(uri.as_ref())
+            .headers(headers)
+            .header(ACCEPT, [EVENT_STREAM_MIME_TYPE, JSON_MIME_TYPE].join(", "));
+        if let Some(auth_header) = auth_header {
+            request = request.bearer_auth(auth_header);
+        }
+        if let Some(session_id) = session_id {
+            request = request.header(HEADER_SESSION_ID, session_id.as_ref());
+        }
+
+        let response = request.json(&message).send().await?;
+        if response.status() == reqwest::StatusCode::UNAUTHORIZED
+            && let Some(header) = response.headers().get(WWW_AUTHENTICATE)
+        {
+            let header = header.to_str().map_err(|_| {
+                StreamableHttpError::UnexpectedServerResponse(
+                    "invalid www-authenticate header value".into(),
+                )
+            })?;
+            return Err(StreamableHttpError::AuthRequired(AuthRequiredError {
+                www_authenticate_header: header.to_string(),
+            }));
+        }
+
+        let status = response.status();
+        let response = response.error_for_status()?;
+        if matches!(
+            status,
+            reqwest::StatusCode::ACCEPTED | reqwest::StatusCode::NO_CONTENT
+        ) {
+            return Ok(StreamableHttpPostResponse::Accepted);
+        }
+        let session_id = response
+            .headers()
+            .get(HEADER_SESSION_ID)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let content_type = response.headers().get(CONTENT_TYPE);
+        match content_type {
+            Some(ct) if ct.as_bytes().starts_with(EVENT_STREAM_MIME_TYPE.as_bytes()) => {
+                let events = SseStream::from_bytes_stream(response.bytes_stream()).boxed();
+                Ok(StreamableHttpPostResponse::Sse(events, session_id))
+            }
+            Some(ct) if ct.as_bytes().starts_with(JSON_MIME_TYPE.as_bytes()) => {
+                let message: ServerJsonRpcMessage = response.json().await?;
+                Ok(StreamableHttpPostResponse::Json(message, session_id))
+            }
+            _ => Err(StreamableHttpError::UnexpectedContentType(
+                content_type.map(|ct| String::from_utf8_lossy(ct.as_bytes()).to_string()),
+            )),
+        }
+    }
+}
+
+impl StreamableHttpClient for PerCallHeadersClient {
+    type Error = reqwest::Error;
+
+    async fn post_message(
+        &self,
+        uri: Arc<str>,
+        message: ClientJsonRpcMessage,
+        session_id: Option<Arc<str>>,
+        auth_header: Option<String>,
+    ) -> Result<StreamableHttpPostResponse, StreamableHttpError<Self::Error>> {
+        let headers = match &message {
+            JsonRpcMessage::Request(request) => request
+                .request
+                .extensions()
+                .get::<CallHeaders>()
+                .map(|CallHeaders(headers)| headers.clone()),
+            _ => None,
+        };
+        match headers {
+            Some(headers) => {
+                self.post_with_headers(uri, message, session_id, auth_header, headers)
+                    .await
+            }
+            None => {
+                self.client
+                    .post_message(uri, message, session_id, auth_header)
+                    .await
+            }
+        }
+    }
+
+    async fn delete_session(
+        &self,
+        uri: Arc<str>,
+        session_id: Arc<str>,
+        auth_header: Option<String>,
+    ) -> Result<(), StreamableHttpError<Self::Error>> {
+        self.client
+            .delete_session(uri, session_id, auth_header)
+            .await
+    }
+
+    async fn get_stream(
+        &self,
+        uri: Arc<str>,
+        session_id: Arc<str>,
+        last_event_id: Option<String>,
+        auth_header: Option<String>,
+    ) -> Result<BoxStream<'static, Result<Sse, SseError>>, StreamableHttpError<Self::Error>> {
+        self.client
+            .get_stream(uri, session_id, last_event_id, auth_header)
+            .await
+    }
+}
+
+/// Builder for creating Streamable HTTP MCP clients
+///
+/// The HTTP counterpart of [`StdioClientBuilder`](super::StdioClientBuilder). Use it
+/// when you need more than a URL and headers, e.g. a custom timeout, client name,
+/// or handlers for server-initiated requests.
+///
+/// # Example
+///
+/// ```ignore
+/// use kodegen_mcp_client::{StreamableClientBuilder, X_KODEGEN_PWD};
+/// use reqwest::header::HeaderValue;
+/// use std::time::Duration;
+///
+/// let (client, _conn) = StreamableClientBuilder::new("http://localhost:8000/mcp")
+///     .header(X_KODEGEN_PWD, HeaderValue::from_static("/project"))
+///     .timeout(Duration::from_secs(60))
+///     .client_name("my-app-client")
+///     .sampling_handler(my_sampler)
+///     .build()
+///     .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct StreamableClientBuilder {
+    url: String,
+    headers: HeaderMap,
+    timeout: Option<Duration>,
+    client_name: Option<String>,
+    handlers: ClientHandlers,
+}
+
+impl StreamableClientBuilder {
+    /// Create a new builder for the given MCP endpoint URL
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            headers: HeaderMap::new(),
+            timeout: None,
+            client_name: None,
+            handlers: ClientHandlers::default(),
+        }
+    }
+
+    /// Add a default header sent with every HTTP request
+    ///
+    /// Accepts `HeaderName` values or static strings such as [`crate::X_KODEGEN_PWD`].
+    #[must_use]
+    pub fn header(mut self, name: impl IntoHeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Add multiple default headers sent with every HTTP request
+    ///
+    /// Headers with the same name replace previously added values.
+    #[must_use]
+    pub fn headers(mut self, headers: HeaderMap) -> Self {
+        self.headers.extend(headers);
+        self
+    }
+
+    /// Set a custom timeout for MCP operations
+    ///
+    /// Default is 10 minutes if not specified.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set a custom client name for MCP identification
+    ///
+    /// Default is "kodegen-streamable-client" if not specified.
+    #[must_use]
+    pub fn client_name(mut self, name: impl Into<String>) -> Self {
+        self.client_name = Some(name.into());
+        self
+    }
+
+    /// Register a handler for `sampling/createMessage` requests
+    ///
+    /// The `sampling` capability is advertised to the server only when a
+    /// handler is registered. See [`SamplingHandler`] for the callback contract.
+    #[must_use]
+    pub fn sampling_handler(mut self, handler: impl SamplingHandler) -> Self {
+        self.handlers.sampling = Some(Arc::new(handler));
+        self
+    }
+
+    /// Register a handler for `elicitation/create` requests
+    ///
+    /// The `elicitation` capability is advertised to the server only when a
+    /// handler is registered. See [`ElicitationHandler`] for the callback contract;
+    /// use `FixtureElicitationHandler` to answer from fixtures in CI.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let builder = StreamableClientBuilder::new("http://localhost:8000/mcp")
+    ///     .elicitation_handler(FixtureElicitationHandler::new()
+    ///         .answer("Confirm deletion", json!({"confirm": true})));
+    /// ```
+    #[must_use]
+    pub fn elicitation_handler(mut self, handler: impl ElicitationHandler) -> Self {
+        self.handlers.elicitation = Some(Arc::new(handler));
+        self
+    }
+
+    /// Register the workspace roots reported to the server via `roots/list`
+    ///
+    /// Advertises the `roots` capability. Pass a `Vec<Root>` for fixed roots or an
+    /// async closure to compute them per request; use `KodegenConnection::set_roots()`
+    /// to change them after connecting.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use kodegen_mcp_client::path_root;
+    ///
+    /// let builder = StreamableClientBuilder::new("http://localhost:8000/mcp")
+    ///     .roots(vec![path_root("/project", Some("project"))]);
+    /// ```
+    #[must_use]
+    pub fn roots(mut self, provider: impl RootsProvider) -> Self {
+        self.handlers.roots = Some(Arc::new(provider));
+        self
+    }
+
+    /// Build and connect the MCP client
+    ///
+    /// Returns a tuple of (client, connection):
+    /// - `client`: Clone-able handle for MCP operations, share freely across tasks
+    /// - `connection`: Lifecycle manager, must be held until shutdown desired
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Connection` if building the HTTP client fails,
+    /// or `ClientError::InitError` if the MCP initialization fails.
+    pub async fn build(self) -> Result<(KodegenClient, KodegenConnection), ClientError> {
+        let client = reqwest::Client::builder()
+            .default_headers(self.headers)
+            .build()
+            .map_err(|e| ClientError::Connection {
+                message: format!("Failed to build HTTP client: {e}"),
+                transport_type: Some(crate::TransportType::Http),
+                endpoint: Some(self.url.clone()),
+            })?;
+
+        let config = StreamableHttpClientTransportConfig {
+            uri: self.url.into(),
+            ..Default::default()
+        };
+
+        let transport =
+            StreamableHttpClientTransport::with_client(PerCallHeadersClient { client }, config);
+
+        let client_info = create_client_info(
+            self.client_name
+                .unwrap_or_else(|| "kodegen-streamable-client".to_string()),
+        );
+
+        let service = KodegenClientHandler::from_parts(client_info, self.handlers)
+            .serve(transport)
+            .await?;
+
+        // Use KodegenConnection to wrap service, then extract client
+        let connection = KodegenConnection::from_service(service);
+        let client = match self.timeout {
+            Some(timeout) => connection.client().with_timeout(timeout),
+            None => connection.client(),
+        };
+
+        Ok((client, connection))
+    }
+}
 
 /// Create an HTTP client from a URL (StreamableHttpService transport)
 ///
@@ -37,6 +364,10 @@ pub async fn create_http_client(
 
 /// Create a Streamable HTTP client from a URL with default headers
 ///
+/// This is a convenience function for simple cases. For advanced configuration
+/// (custom timeout, client name, sampling/elicitation handlers, roots, etc.), use
+/// [`StreamableClientBuilder`] instead.
+///
 /// Returns a tuple of (client, connection):
 /// - `client`: Clone-able handle for MCP operations, share freely across tasks
 /// - `connection`: Lifecycle manager, must be held until shutdown desired
@@ -64,31 +395,8 @@ pub async fn create_streamable_client(
     url: &str,
     headers: HeaderMap,
 ) -> Result<(KodegenClient, KodegenConnection), ClientError> {
-    let client = reqwest::Client::builder()
-        .default_headers(headers)
+    StreamableClientBuilder::new(url)
+        .headers(headers)
         .build()
-        .map_err(|e| ClientError::Connection {
-            message: format!("Failed to build HTTP client: {e}"),
-            transport_type: Some(crate::TransportType::Http),
-            endpoint: Some(url.to_string()),
-        })?;
-
-    let config = StreamableHttpClientTransportConfig {
-        uri: url.into(),
-        ..Default::default()
-    };
-
-    let transport = StreamableHttpClientTransport::with_client(client, config);
-
-    let client_info = create_client_info("kodegen-streamable-client");
-
-    let service = client_info
-        .serve(transport)
-        .await?;
-
-    // Use KodegenConnection to wrap service, then extract client
-    let connection = KodegenConnection::from_service(service);
-    let client = connection.client();
-
-    Ok((client, connection))
+        .await
 }
@@ -4,7 +4,7 @@ use rmcp::model::{ClientCapabilities, ClientInfo, Implementation};
 pub mod http;
 pub mod stdio;
 
-pub use http::create_streamable_client;
+pub use http::{StreamableClientBuilder, create_streamable_client};
 pub use stdio::{StdioClientBuilder, create_stdio_client};
 
 /// Create standard ClientInfo for kodegen MCP clients
@@ -1,8 +1,13 @@
 // packages/mcp-client/src/transports/stdio.rs
 use super::create_client_info;
-use crate::{ClientError, KodegenClient, KodegenConnection};
+use crate::{
+    ClientError, KodegenClient, KodegenConnection,
+    handler::{
+        ClientHandlers, ElicitationHandler, KodegenClientHandler, RootsProvider, SamplingHandler,
+    },
+};
 use rmcp::{ServiceExt, transport::TokioChildProcess};
-use std::{collections::HashMap, path::PathBuf};
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
 use tokio::{process::Command, time::Duration};
 
 /// Default timeout for MCP operations
@@ -25,6 +30,7 @@ const DEFAULT_TIMEOUT: Duration = Duration::from_secs(12);
 /// - Working directory
 /// - Operation timeout
 /// - Client identification
+/// - Handlers for server-initiated requests (sampling, roots, elicitation)
 ///
 /// # Example
 ///
@@ -55,6 +61,7 @@ pub struct StdioClientBuilder {
     current_dir: Option<PathBuf>,
     timeout: Duration,
     client_name: Option<String>,
+    handlers: ClientHandlers,
 }
 
 impl StdioClientBuilder {
@@ -79,6 +86,7 @@ impl StdioClientBuilder {
             current_dir: None,
             timeout: DEFAULT_TIMEOUT,
             client_name: None,
+            handlers: ClientHandlers::default(),
         }
     }
 
@@ -298,6 +306,64 @@ impl StdioClientBuilder {
         self
     }
 
+    /// Register a handler for `sampling/createMessage` requests
+    ///
+    /// The `sampling` capability is advertised to the server only when a
+    /// handler is registered. See [`SamplingHandler`] for the callback contract.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let builder = StdioClientBuilder::new("kodegen")
+    ///     .sampling_handler(|params: CreateMessageRequestParam| async move {
+    ///         my_llm.create_message(params).await
+    ///     });
+    /// ```
+    #[must_use]
+    pub fn sampling_handler(mut self, handler: impl SamplingHandler) -> Self {
+        self.handlers.sampling = Some(Arc::new(handler));
+        self
+    }
+
+    /// Register a handler for `elicitation/create` requests
+    ///
+    /// The `elicitation` capability is advertised to the server only when a
+    /// handler is registered. See [`ElicitationHandler`] for the callback contract;
+    /// use `FixtureElicitationHandler` to answer from fixtures in CI.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let builder = StdioClientBuilder::new("kodegen")
+    ///     .elicitation_handler(FixtureElicitationHandler::new()
+    ///         .answer("Confirm deletion", json!({"confirm": true})));
+    /// ```
+    #[must_use]
+    pub fn elicitation_handler(mut self, handler: impl ElicitationHandler) -> Self {
+        self.handlers.elicitation = Some(Arc::new(handler));
+        self
+    }
+
+    /// Register the workspace roots reported to the server via `roots/list`
+    ///
+    /// Advertises the `roots` capability. Pass a `Vec<Root>` for fixed roots or an
+    /// async closure to compute them per request; use `KodegenConnection::set_roots()`
+    /// to change them after connecting.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use kodegen_mcp_client::path_root;
+    ///
+    /// let builder = StdioClientBuilder::new("kodegen")
+    ///     .roots(vec![path_root("/project", Some("project"))]);
+    /// ```
+    #[must_use]
+    pub fn roots(mut self, provider: impl RootsProvider) -> Self {
+        self.handlers.roots = Some(Arc::new(provider));
+        self
+    }
+
     /// Build and connect the MCP client
     ///
     /// Returns a tuple of (client, connection):
@@ -415,7 +481,7 @@ impl StdioClientBuilder {
         );
 
         // Initialize MCP connection
-        let service = client_info
+        let service = KodegenClientHandler::from_parts(client_info, self.handlers)
             .serve(transport)
             .await?;
 
@@ -1,16 +1,26 @@
 //! Validation utilities for response deserialization
 //!
 //! Provides custom serde deserializers that validate data during deserialization,
-//! ensuring invalid API responses fail fast with clear error messages.
+//! ensuring invalid API responses fail fast with clear error messages, and the
+//! [`Validate`] trait for post-deserialization checks that report every
+//! violation at once with its JSON pointer.
 
 use serde::{Deserialize, Deserializer};
+use std::fmt::Display;
+
+/// Derive [`Validate`] from `#[validate(...)]` field attributes
+///
+/// See the `kodegen_mcp_client_derive` crate for the supported rules.
+pub use kodegen_mcp_client_derive::Validate;
 
 /// Deserialize a non-empty string
 ///
 /// Validates that deserialized string is not empty.
 ///
 /// # Example
-/// ```rust
+/// ```no_run
+/// # use kodegen_mcp_client::validation::deserialize_non_empty_string;
+/// # use serde::Deserialize;
 /// #[derive(Deserialize)]
 /// struct Response {
 ///     #[serde(deserialize_with = "deserialize_non_empty_string")]
@@ -36,7 +46,9 @@ where
 /// Validates that deserialized number is strictly positive.
 ///
 /// # Example
-/// ```rust
+/// ```no_run
+/// # use kodegen_mcp_client::validation::deserialize_positive_i64;
+/// # use serde::Deserialize;
 /// #[derive(Deserialize)]
 /// struct Response {
 ///     #[serde(deserialize_with = "deserialize_positive_i64")]
@@ -65,7 +77,9 @@ where
 /// Validates that deserialized number is strictly positive (not zero).
 ///
 /// # Example
-/// ```rust
+/// ```no_run
+/// # use kodegen_mcp_client::validation::deserialize_positive_u64;
+/// # use serde::Deserialize;
 /// #[derive(Deserialize)]
 /// struct GitHubUser {
 ///     #[serde(deserialize_with = "deserialize_positive_u64")]
@@ -111,13 +125,207 @@ where
 /// Trait for types that can perform post-deserialization validation
 ///
 /// Use this for complex invariants that can't be checked during deserialization,
-/// such as cross-field validation or array length checks.
+/// such as cross-field validation or array length checks. Unlike the serde
+/// deserializers above, validation does not stop at the first failure: every
+/// violation is collected into [`ValidationErrors`], and nested structs
+/// contribute theirs under their own JSON pointer.
+///
+/// `KodegenClient::call_tool_validated()` calls `validate()` after deserializing.
+/// The default implementation accepts everything, so types without invariants
+/// opt in with an empty impl. Most types use the derive macro instead:
+///
+/// ```ignore
+/// #[derive(Deserialize, Validate)]
+/// pub struct GitHubIssue {
+///     #[validate(nested)]
+///     pub user: GitHubUser,
+///     // ...
+/// }
+///
+/// #[derive(Deserialize, Validate)]
+/// pub struct GitHubIssuesResponse {
+///     pub count: u64,
+///     #[validate(len_eq = "count", nested)]
+///     pub issues: Vec<GitHubIssue>,
+/// }
+/// ```
 pub trait Validate {
+    /// Record every violation of this value's invariants in `errors`
+    ///
+    /// Paths are relative to `self`; use [`ValidationErrors::nested`] to
+    /// validate child values under their own path.
+    fn validate_into(&self, errors: &mut ValidationErrors) {
+        let _ = errors;
+    }
+
     /// Validate the data structure's invariants
     ///
     /// # Errors
-    /// Returns error message if validation fails
-    fn validate(&self) -> Result<(), String>;
+    /// Returns every violation found, each with the JSON pointer of the
+    /// offending value
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        self.validate_into(&mut errors);
+        errors.into_result()
+    }
+}
+
+/// Untyped JSON has no invariants to check
+impl Validate for serde_json::Value {}
+
+/// Validates each element under its index (`/0`, `/1`, ...)
+impl<T: Validate> Validate for Vec<T> {
+    fn validate_into(&self, errors: &mut ValidationErrors) {
+        for (idx, item) in self.iter().enumerate() {
+            errors.nested(idx, item);
+        }
+    }
+}
+
+/// Validates the value if present
+impl<T: Validate> Validate for Option<T> {
+    fn validate_into(&self, errors: &mut ValidationErrors) {
+        if let Some(value) = self {
+            value.validate_into(errors);
+        }
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for Box<T> {
+    fn validate_into(&self, errors: &mut ValidationErrors) {
+        (**self).validate_into(errors);
+    }
+}
+
+/// A single failed invariant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// JSON pointer to the offending value (empty for the value itself)
+    pub path: String,
+    /// Human-readable description of the failure
+    pub message: String,
+}
+
+impl std::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = if self.path.is_empty() { "/" } else { &self.path };
+        write!(f, "{}: {}", path, self.message)
+    }
+}
+
+/// Every invariant a value failed, collected by [`Validate::validate`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationErrors {
+    errors: Vec<FieldError>,
+}
+
+impl ValidationErrors {
+    /// Create an empty collection
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a failure of the field `field` of the value being validated
+    pub fn add(&mut self, field: &str, message: impl Into<String>) {
+        self.errors.push(FieldError {
+            path: format!("/{}", escape_pointer_segment(field)),
+            message: message.into(),
+        });
+    }
+
+    /// Record a failure of the value being validated as a whole
+    pub fn add_root(&mut self, message: impl Into<String>) {
+        self.errors.push(FieldError {
+            path: String::new(),
+            message: message.into(),
+        });
+    }
+
+    /// Validate `value` and record its failures under `/segment`
+    pub fn nested<V: Validate + ?Sized>(&mut self, segment: impl Display, value: &V) {
+        let mut nested = ValidationErrors::new();
+        value.validate_into(&mut nested);
+        let prefix = format!("/{}", escape_pointer_segment(&segment.to_string()));
+        self.errors
+            .extend(nested.errors.into_iter().map(|error| FieldError {
+                path: format!("{}{}", prefix, error.path),
+                message: error.message,
+            }));
+    }
+
+    /// Whether no failures were recorded
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Number of recorded failures
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Iterate over the recorded failures in the order they were found
+    pub fn iter(&self) -> std::slice::Iter<'_, FieldError> {
+        self.errors.iter()
+    }
+
+    /// `Ok(())` if nothing was recorded, otherwise `Err(self)`
+    ///
+    /// # Errors
+    /// Returns `self` if any failure was recorded
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() { Ok(()) } else { Err(self) }
+    }
+}
+
+impl std::fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, error) in self.errors.iter().enumerate() {
+            if idx > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+impl IntoIterator for ValidationErrors {
+    type Item = FieldError;
+    type IntoIter = std::vec::IntoIter<FieldError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ValidationErrors {
+    type Item = &'a FieldError;
+    type IntoIter = std::slice::Iter<'a, FieldError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+/// Escape a JSON pointer reference token (RFC 6901)
+fn escape_pointer_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// Check that every string in a list is non-empty
+///
+/// The `#[validate(custom = "non_empty_strings")]` counterpart of
+/// [`deserialize_vec_non_empty_strings`].
+///
+/// # Errors
+/// Returns error naming the index of the first empty string
+pub fn non_empty_strings(strings: &[String]) -> Result<(), String> {
+    match strings.iter().position(String::is_empty) {
+        Some(idx) => Err(format!("string at index {} cannot be empty", idx)),
+        None => Ok(()),
+    }
 }
 
 /// Helper to format count mismatch errors consistently
@@ -128,3 +336,27 @@ pub fn count_mismatch_error(field_name: &str, count: usize, actual: usize) -> St
         field_name, count, actual
     )
 }
+
+/// Helper to format empty field errors consistently
+#[inline]
+pub fn empty_field_error(field_name: &str) -> String {
+    format!("{} cannot be empty", field_name)
+}
+
+/// Helper to format lower bound violations consistently
+#[inline]
+pub fn below_minimum_error(field_name: &str, value: impl Display, min: impl Display) -> String {
+    format!(
+        "{} value ({}) is less than minimum ({})",
+        field_name, value, min
+    )
+}
+
+/// Helper to format upper bound violations consistently
+#[inline]
+pub fn above_maximum_error(field_name: &str, value: impl Display, max: impl Display) -> String {
+    format!(
+        "{} value ({}) is greater than maximum ({})",
+        field_name, value, max
+    )
+}
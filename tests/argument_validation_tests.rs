@@ -0,0 +1,228 @@
+// Integration tests for client-side tool argument validation
+use kodegen_mcp_client::{
+    ClientError, KodegenClientHandler, KodegenConnection, ServerNotification,
+};
+use rmcp::{
+    ErrorData, RoleServer, ServerHandler, ServiceExt,
+    model::{
+        CallToolRequestParam, CallToolResult, ListToolsResult, PaginatedRequestParam,
+        ServerCapabilities, ServerInfo, Tool,
+    },
+    service::RequestContext,
+};
+use serde_json::json;
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+use tokio::time::Instant;
+use tokio_stream::StreamExt;
+
+/// In-process server with an "add" tool requiring integer `a` and `b`
+///
+/// "relax" drops the requirement on `b` and announces `tools/list_changed`.
+/// Every call and listing is counted.
+#[derive(Clone, Default)]
+struct SchemaServer {
+    /// Never answer `tools/list`
+    hang_listing: bool,
+    relaxed: Arc<AtomicBool>,
+    calls: Arc<AtomicUsize>,
+    listings: Arc<AtomicUsize>,
+}
+
+impl SchemaServer {
+    fn add_tool(&self) -> Tool {
+        let required = if self.relaxed.load(Ordering::SeqCst) {
+            json!(["a"])
+        } else {
+            json!(["a", "b"])
+        };
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "a": {"type": "integer"},
+                "b": {"type": "integer"}
+            },
+            "required": required
+        });
+        Tool::new(
+            "add",
+            "Add two integers",
+            Arc::new(schema.as_object().expect("Schema is an object").clone()),
+        )
+    }
+}
+
+impl ServerHandler for SchemaServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_tool_list_changed()
+                .build(),
+            ..Default::default()
+        }
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, ErrorData> {
+        self.listings.fetch_add(1, Ordering::SeqCst);
+        if self.hang_listing {
+            std::future::pending::<()>().await;
+        }
+        Ok(ListToolsResult {
+            tools: vec![
+                self.add_tool(),
+                Tool::new(
+                    "relax",
+                    "Relax the add schema",
+                    Arc::new(Default::default()),
+                ),
+            ],
+            next_cursor: None,
+            meta: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        if request.name == "relax" {
+            self.relaxed.store(true, Ordering::SeqCst);
+            context
+                .peer
+                .notify_tool_list_changed()
+                .await
+                .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+        }
+        Ok(CallToolResult::structured(json!({
+            "arguments": request.arguments,
+        })))
+    }
+}
+
+async fn connect(server: SchemaServer) -> KodegenConnection {
+    let (server_io, client_io) = tokio::io::duplex(4096);
+    tokio::spawn(async move {
+        let server = server.serve(server_io).await?;
+        server.waiting().await?;
+        anyhow::Ok(())
+    });
+
+    let service = KodegenClientHandler::new("test-client")
+        .serve(client_io)
+        .await
+        .expect("Failed to initialize client");
+    KodegenConnection::from_service(service)
+}
+
+/// Test that invalid arguments fail locally with every violation listed
+#[tokio::test]
+async fn test_invalid_arguments_rejected_locally() {
+    let server = SchemaServer::default();
+    let conn = connect(server.clone()).await;
+
+    match conn.client().call_tool("add", json!({"a": "one"})).await {
+        Err(ClientError::InvalidArguments {
+            tool_name,
+            violations,
+        }) => {
+            assert_eq!(tool_name, "add");
+            let mut paths: Vec<_> = violations
+                .iter()
+                .map(|v| v.instance_path.as_str())
+                .collect();
+            paths.sort_unstable();
+            // Missing "b" is reported at the root, the wrong type at "/a"
+            assert_eq!(paths, ["", "/a"]);
+        }
+        other => panic!("Expected InvalidArguments error, got: {:?}", other),
+    }
+    assert_eq!(server.calls.load(Ordering::SeqCst), 0);
+
+    // Valid arguments go through, reusing the cached schemas
+    conn.client()
+        .call_tool("add", json!({"a": 1, "b": 2}))
+        .await
+        .expect("Valid arguments should be sent");
+    assert_eq!(server.calls.load(Ordering::SeqCst), 1);
+    assert_eq!(server.listings.load(Ordering::SeqCst), 1);
+}
+
+/// Test that validation can be turned off for sloppy servers
+#[tokio::test]
+async fn test_argument_validation_opt_out() {
+    let server = SchemaServer::default();
+    let conn = connect(server.clone()).await;
+
+    let result = conn
+        .client()
+        .with_argument_validation(false)
+        .call_tool("add", json!({"a": "one"}))
+        .await
+        .expect("Unvalidated call should reach the server");
+    assert_eq!(
+        result.structured_content,
+        Some(json!({"arguments": {"a": "one"}}))
+    );
+    assert_eq!(server.listings.load(Ordering::SeqCst), 0);
+}
+
+/// Test that tools/list_changed refreshes the cached schemas
+#[tokio::test]
+async fn test_list_changed_invalidates_schemas() {
+    let server = SchemaServer::default();
+    let conn = connect(server.clone()).await;
+    let client = conn.client();
+    let mut notifications = client.subscribe();
+
+    let result = client.call_tool("add", json!({"a": 1})).await;
+    assert!(matches!(result, Err(ClientError::InvalidArguments { .. })));
+
+    client
+        .call_tool("relax", json!({}))
+        .await
+        .expect("Failed to call tool");
+    while notifications.next().await != Some(ServerNotification::ToolListChanged) {}
+
+    client
+        .call_tool("add", json!({"a": 1}))
+        .await
+        .expect("Relaxed schema should accept the call");
+    assert_eq!(server.listings.load(Ordering::SeqCst), 2);
+}
+
+/// Test that a hung tools/list delays the first call briefly, not by the call timeout
+#[tokio::test(start_paused = true)]
+async fn test_hung_listing_skips_validation() {
+    let server = SchemaServer {
+        hang_listing: true,
+        ..SchemaServer::default()
+    };
+    let conn = connect(server.clone()).await;
+
+    let started = Instant::now();
+    let result = conn
+        .client()
+        .call_tool("add", json!({"a": 1}))
+        .await
+        .expect("Call should be sent unvalidated");
+    let elapsed = started.elapsed();
+    assert!(elapsed >= Duration::from_secs(5), "{:?}", elapsed);
+    assert!(elapsed < Duration::from_secs(10), "{:?}", elapsed);
+    assert_eq!(
+        result.structured_content,
+        Some(json!({"arguments": {"a": 1}}))
+    );
+    assert_eq!(server.calls.load(Ordering::SeqCst), 1);
+}
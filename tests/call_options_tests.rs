@@ -0,0 +1,377 @@
+// Integration tests for per-call options
+use kodegen_mcp_client::{
+    CallOptions, CancellationToken, ClientError, KodegenClientHandler, KodegenConnection,
+    RetryPolicy, StreamableClientBuilder, ToolProgress,
+};
+use reqwest::header::HeaderValue;
+use rmcp::{
+    ErrorData, RoleServer, ServerHandler, ServiceExt,
+    model::{
+        CallToolRequestParam, CallToolResult, ListToolsResult, PaginatedRequestParam,
+        ProgressNotificationParam, ServerCapabilities, ServerInfo, Tool,
+    },
+    service::RequestContext,
+    transport::streamable_http_server::{
+        StreamableHttpService, session::local::LocalSessionManager,
+    },
+};
+use serde_json::json;
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+/// In-process server for exercising call options
+///
+/// - "meta" echoes the request's `_meta` and the `x-trace` HTTP header
+/// - "slow" sleeps for the number of milliseconds in `ms`
+/// - "flaky" is slow on its first call only
+/// - "steps" reports progress twice
+#[derive(Clone, Default)]
+struct OptionsServer {
+    flaky_calls: Arc<AtomicUsize>,
+}
+
+impl ServerHandler for OptionsServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            ..Default::default()
+        }
+    }
+
+    async fn list_tools(
+        &self,
+        request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, ErrorData> {
+        let cursor = request.and_then(|r| r.cursor);
+        let (name, next_cursor) = match cursor.as_deref() {
+            None => ("first", Some("page-2".to_string())),
+            _ => ("second", None),
+        };
+        Ok(ListToolsResult {
+            tools: vec![Tool::new(name, "test tool", Arc::new(Default::default()))],
+            next_cursor,
+            meta: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        match request.name.as_ref() {
+            "meta" => {
+                let header = context
+                    .extensions
+                    .get::<http::request::Parts>()
+                    .and_then(|parts| parts.headers.get("x-trace"))
+                    .and_then(|value| value.to_str().ok())
+                    .map(str::to_string);
+                Ok(CallToolResult::structured(json!({
+                    "meta": context.meta.0,
+                    "header": header,
+                })))
+            }
+            "slow" => {
+                let ms = request
+                    .arguments
+                    .as_ref()
+                    .and_then(|args| args.get("ms"))
+                    .and_then(|ms| ms.as_u64())
+                    .unwrap_or(5000);
+                tokio::time::sleep(Duration::from_millis(ms)).await;
+                Ok(CallToolResult::structured(json!({"slept": ms})))
+            }
+            "flaky" => {
+                if self.flaky_calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                }
+                Ok(CallToolResult::structured(json!({"ok": true})))
+            }
+            "steps" => {
+                let token = context
+                    .meta
+                    .get_progress_token()
+                    .ok_or_else(|| ErrorData::invalid_params("missing progress token", None))?;
+                for step in 1..=2 {
+                    context
+                        .peer
+                        .notify_progress(ProgressNotificationParam {
+                            progress_token: token.clone(),
+                            progress: f64::from(step),
+                            total: Some(2.0),
+                            message: None,
+                        })
+                        .await
+                        .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+                }
+                Ok(CallToolResult::structured(json!({"token": token})))
+            }
+            other => Err(ErrorData::invalid_params(
+                format!("unknown tool {other}"),
+                None,
+            )),
+        }
+    }
+}
+
+async fn connect(server: OptionsServer) -> KodegenConnection {
+    let (server_io, client_io) = tokio::io::duplex(4096);
+    tokio::spawn(async move {
+        let server = server.serve(server_io).await?;
+        server.waiting().await?;
+        anyhow::Ok(())
+    });
+
+    let service = KodegenClientHandler::new("test-client")
+        .serve(client_io)
+        .await
+        .expect("Failed to initialize client");
+    KodegenConnection::from_service(service)
+}
+
+/// Serve `OptionsServer` over Streamable HTTP and return its endpoint URL
+async fn serve_http() -> String {
+    let service = StreamableHttpService::new(
+        || Ok(OptionsServer::default()),
+        LocalSessionManager::default().into(),
+        Default::default(),
+    );
+    let router = axum::Router::new().nest_service("/mcp", service);
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind");
+    let addr = listener.local_addr().expect("Failed to get address");
+    tokio::spawn(async move { axum::serve(listener, router).await });
+    format!("http://{addr}/mcp")
+}
+
+/// Test that a per-call timeout overrides the handle timeout in both directions
+#[tokio::test]
+async fn test_timeout_override() {
+    let conn = connect(OptionsServer::default()).await;
+    let short = conn.client().with_timeout(Duration::from_millis(50));
+
+    let result = short
+        .call_tool_with(
+            "slow",
+            json!({"ms": 150}),
+            &CallOptions::new().timeout(Duration::from_secs(5)),
+        )
+        .await
+        .expect("Per-call timeout should allow the slow call");
+    assert_eq!(result.structured_content, Some(json!({"slept": 150})));
+
+    match conn
+        .client()
+        .call_tool_with(
+            "slow",
+            json!({"ms": 5000}),
+            &CallOptions::new().timeout(Duration::from_millis(50)),
+        )
+        .await
+    {
+        Err(ClientError::Timeout { duration, .. }) => {
+            assert_eq!(duration, Duration::from_millis(50));
+        }
+        other => panic!("Expected Timeout error, got: {:?}", other),
+    }
+}
+
+/// Test that an absolute deadline bounds the call
+#[tokio::test]
+async fn test_deadline() {
+    let conn = connect(OptionsServer::default()).await;
+    let deadline = tokio::time::Instant::now() + Duration::from_millis(100);
+
+    let started = tokio::time::Instant::now();
+    let result = conn
+        .client()
+        .call_tool_with(
+            "slow",
+            json!({"ms": 5000}),
+            &CallOptions::new().deadline(deadline),
+        )
+        .await;
+    assert!(matches!(result, Err(ClientError::Timeout { .. })));
+    assert!(started.elapsed() < Duration::from_secs(2));
+}
+
+/// Test that meta fields and a custom progress token reach the server
+#[tokio::test]
+async fn test_meta_and_progress_token() {
+    let conn = connect(OptionsServer::default()).await;
+    let options = CallOptions::new()
+        .meta("traceId", "req-42")
+        .progress_token("my-token");
+
+    let result = conn
+        .client()
+        .call_tool_with("meta", json!({}), &options)
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(
+        result.structured_content,
+        Some(json!({
+            "meta": {"traceId": "req-42", "progressToken": "my-token"},
+            "header": null,
+        }))
+    );
+}
+
+/// Test that progress updates are delivered through the options
+#[tokio::test]
+async fn test_on_progress() {
+    let conn = connect(OptionsServer::default()).await;
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+    conn.client()
+        .call_tool_with("steps", json!({}), &CallOptions::new().on_progress(tx))
+        .await
+        .expect("Failed to call tool");
+
+    let mut updates = Vec::new();
+    while let Ok(update) = rx.try_recv() {
+        updates.push(update);
+    }
+    assert_eq!(
+        updates,
+        [1.0, 2.0].map(|progress| ToolProgress {
+            progress,
+            total: Some(2.0),
+            message: None,
+        })
+    );
+}
+
+/// Test that the cancellation token aborts the call
+#[tokio::test]
+async fn test_cancellation_option() {
+    let conn = connect(OptionsServer::default()).await;
+    let token = CancellationToken::new();
+    let canceller = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        canceller.cancel();
+    });
+
+    let result = conn
+        .client()
+        .call_tool_with("slow", json!({}), &CallOptions::new().cancellation(token))
+        .await;
+    assert!(matches!(result, Err(ClientError::Cancelled { .. })));
+}
+
+/// Test that a timed-out attempt is retried
+#[tokio::test]
+async fn test_retry_after_timeout() {
+    let server = OptionsServer::default();
+    let calls = server.flaky_calls.clone();
+    let conn = connect(server).await;
+    let options = CallOptions::new()
+        .timeout(Duration::from_millis(100))
+        .retry(RetryPolicy::new(2).backoff(Duration::from_millis(10), Duration::from_millis(10)));
+
+    let result = conn
+        .client()
+        .call_tool_with("flaky", json!({}), &options)
+        .await
+        .expect("Retry should succeed");
+    assert_eq!(result.structured_content, Some(json!({"ok": true})));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    // Without a retry policy the first failure is final
+    calls.store(0, Ordering::SeqCst);
+    let result = conn
+        .client()
+        .call_tool_with(
+            "flaky",
+            json!({}),
+            &CallOptions::new().timeout(Duration::from_millis(100)),
+        )
+        .await;
+    assert!(matches!(result, Err(ClientError::Timeout { .. })));
+}
+
+/// Test list_tools_with and call_tool_typed_with
+#[tokio::test]
+async fn test_list_tools_and_typed_with_options() {
+    let conn = connect(OptionsServer::default()).await;
+    let client = conn.client();
+    let options = CallOptions::new().timeout(Duration::from_secs(5));
+
+    let tools = client
+        .list_tools_with(&options)
+        .await
+        .expect("Failed to list tools");
+    let names: Vec<_> = tools.iter().map(|t| t.name.as_ref()).collect();
+    assert_eq!(names, ["first", "second"]);
+
+    #[derive(serde::Deserialize)]
+    struct Slept {
+        slept: u64,
+    }
+    let slept: Slept = client
+        .call_tool_typed_with("slow", json!({"ms": 1}), &options)
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(slept.slept, 1);
+}
+
+/// Test that per-call headers are sent on HTTP and override defaults
+#[tokio::test]
+async fn test_per_call_http_headers() {
+    let url = serve_http().await;
+    let (client, _conn) = StreamableClientBuilder::new(url)
+        .header("x-trace", HeaderValue::from_static("default"))
+        .build()
+        .await
+        .expect("Failed to connect");
+
+    let header_of = |result: CallToolResult| result.structured_content.unwrap()["header"].clone();
+
+    let result = client
+        .call_tool("meta", json!({}))
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(header_of(result), json!("default"));
+
+    let options = CallOptions::new().header("x-trace", HeaderValue::from_static("per-call"));
+    let result = client
+        .call_tool_with("meta", json!({}), &options)
+        .await
+        .expect("Failed to call tool");
+    let meta = result.structured_content.as_ref().unwrap()["meta"].clone();
+    assert_eq!(header_of(result), json!("per-call"));
+    // Headers travel outside the MCP payload: no progress token of our own
+    let token = meta["progressToken"].as_str().unwrap_or_default();
+    assert!(!token.starts_with("kodegen-"), "{}", meta);
+
+    // Several concurrent calls each get their own headers over the shared client
+    let calls = ["one", "two", "three"].map(|trace| {
+        let client = client.clone();
+        async move {
+            let options = CallOptions::new().header("x-trace", HeaderValue::from_static(trace));
+            let result = client
+                .call_tool_with("meta", json!({}), &options)
+                .await
+                .expect("Failed to call tool");
+            result.structured_content.unwrap()["header"].clone()
+        }
+    });
+    let headers = futures::future::join_all(calls).await;
+    assert_eq!(headers, [json!("one"), json!("two"), json!("three")]);
+
+    // The override applies to that call only
+    let result = client
+        .call_tool("meta", json!({}))
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(header_of(result), json!("default"));
+}
@@ -0,0 +1,164 @@
+// Integration tests for cancelling in-flight tool calls
+use kodegen_mcp_client::{CancellationToken, ClientError, KodegenClientHandler, KodegenConnection};
+use rmcp::{
+    ErrorData, RoleServer, ServerHandler, ServiceExt,
+    model::{
+        CallToolRequestParam, CallToolResult, CancelledNotificationParam, ListToolsResult,
+        PaginatedRequestParam, ServerCapabilities, ServerInfo,
+    },
+    service::{NotificationContext, RequestContext},
+};
+use serde_json::json;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// In-process server that reports every `notifications/cancelled` it receives
+///
+/// "slow" takes five seconds; "fast" returns immediately.
+#[derive(Clone)]
+struct SlowServer {
+    cancelled: mpsc::UnboundedSender<CancelledNotificationParam>,
+}
+
+impl ServerHandler for SlowServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            ..Default::default()
+        }
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, ErrorData> {
+        Ok(ListToolsResult::default())
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        if request.name == "slow" {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        }
+        Ok(CallToolResult::structured(json!({"done": true})))
+    }
+
+    async fn on_cancelled(
+        &self,
+        params: CancelledNotificationParam,
+        _context: NotificationContext<RoleServer>,
+    ) {
+        let _ = self.cancelled.send(params);
+    }
+}
+
+async fn connect() -> (
+    KodegenConnection,
+    mpsc::UnboundedReceiver<CancelledNotificationParam>,
+) {
+    let (tx, rx) = mpsc::unbounded_channel();
+    let (server_io, client_io) = tokio::io::duplex(4096);
+    tokio::spawn(async move {
+        let server = SlowServer { cancelled: tx }.serve(server_io).await?;
+        server.waiting().await?;
+        anyhow::Ok(())
+    });
+
+    let service = KodegenClientHandler::new("test-client")
+        .serve(client_io)
+        .await
+        .expect("Failed to initialize client");
+    (KodegenConnection::from_service(service), rx)
+}
+
+async fn next_cancelled(
+    rx: &mut mpsc::UnboundedReceiver<CancelledNotificationParam>,
+) -> CancelledNotificationParam {
+    tokio::time::timeout(Duration::from_secs(2), rx.recv())
+        .await
+        .expect("Server never received notifications/cancelled")
+        .expect("Server channel closed")
+}
+
+/// Test that a timed-out call_tool tells the server to stop
+#[tokio::test]
+async fn test_call_tool_timeout_notifies_server() {
+    let (conn, mut cancelled) = connect().await;
+    let client = conn.client().with_timeout(Duration::from_millis(100));
+
+    match client.call_tool("slow", json!({})).await {
+        Err(ClientError::Timeout { operation, .. }) => assert!(operation.contains("slow")),
+        other => panic!("Expected Timeout error, got: {:?}", other),
+    }
+
+    let params = next_cancelled(&mut cancelled).await;
+    assert!(params.reason.unwrap_or_default().contains("timed out"));
+}
+
+/// Test that cancelling the token sends the handle's request id
+#[tokio::test]
+async fn test_cancellation_token_cancels_call() {
+    let (conn, mut cancelled) = connect().await;
+    let token = CancellationToken::new();
+
+    let call = conn
+        .client()
+        .call_tool_cancellable("slow", json!({}), token.clone())
+        .await
+        .expect("Failed to start call");
+    let request_id = call.request_id().clone();
+    token.cancel();
+
+    match call.await {
+        Err(ClientError::Cancelled { operation, reason }) => {
+            assert!(operation.contains("slow"));
+            assert_eq!(reason, None);
+        }
+        other => panic!("Expected Cancelled error, got: {:?}", other),
+    }
+    let params = next_cancelled(&mut cancelled).await;
+    assert_eq!(params.request_id, request_id);
+}
+
+/// Test that a reason given to the handle reaches the server
+#[tokio::test]
+async fn test_handle_cancel_sends_reason() {
+    let (conn, mut cancelled) = connect().await;
+
+    let call = conn
+        .client()
+        .call_tool_cancellable("slow", json!({}), CancellationToken::new())
+        .await
+        .expect("Failed to start call");
+    call.cancel(Some("User aborted".to_string()));
+
+    match call.result().await {
+        Err(ClientError::Cancelled { reason, .. }) => {
+            assert_eq!(reason.as_deref(), Some("User aborted"));
+        }
+        other => panic!("Expected Cancelled error, got: {:?}", other),
+    }
+    let params = next_cancelled(&mut cancelled).await;
+    assert_eq!(params.reason.as_deref(), Some("User aborted"));
+}
+
+/// Test that an uncancelled call completes normally and sends nothing
+#[tokio::test]
+async fn test_cancellable_call_completes() {
+    let (conn, mut cancelled) = connect().await;
+
+    let result = conn
+        .client()
+        .call_tool_cancellable("fast", json!({}), CancellationToken::new())
+        .await
+        .expect("Failed to start call")
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(result.structured_content, Some(json!({"done": true})));
+
+    assert!(cancelled.try_recv().is_err());
+}
@@ -0,0 +1,343 @@
+// Integration tests for server-initiated requests handled by KodegenClientHandler
+use kodegen_mcp_client::{
+    ClientError, ElicitationResponse, FixtureElicitationHandler, KodegenClientHandler,
+    KodegenConnection, path_root,
+};
+use rmcp::{
+    ErrorData, RoleServer, ServerHandler, ServiceExt,
+    model::{
+        CallToolRequestParam, CallToolResult, ClientResult, Content, CreateElicitationRequest,
+        CreateElicitationRequestParam, CreateMessageRequestParam, CreateMessageResult,
+        ElicitationSchema, ListToolsResult, PaginatedRequestParam, Role, Root, SamplingMessage,
+        ServerCapabilities, ServerInfo, ServerRequest,
+    },
+    service::{NotificationContext, RequestContext},
+};
+use serde_json::json;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::Notify;
+
+/// In-process server whose tools call back into the client
+#[derive(Clone, Default)]
+struct CallbackServer {
+    roots_changed: Arc<Notify>,
+}
+
+impl ServerHandler for CallbackServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            ..Default::default()
+        }
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, ErrorData> {
+        Ok(ListToolsResult::default())
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let capabilities = context
+            .peer
+            .peer_info()
+            .map(|info| info.capabilities.clone())
+            .unwrap_or_default();
+
+        match request.name.as_ref() {
+            "capabilities" => Ok(CallToolResult::structured(json!({
+                "sampling": capabilities.sampling.is_some(),
+                "roots": capabilities.roots.is_some(),
+                "elicitation": capabilities.elicitation.is_some(),
+            }))),
+            "elicit" => {
+                let message = request
+                    .arguments
+                    .as_ref()
+                    .and_then(|args| args.get("message"))
+                    .and_then(|m| m.as_str())
+                    .unwrap_or_default()
+                    .to_string();
+                let schema = ElicitationSchema::builder()
+                    .required_string("branch")
+                    .build()
+                    .map_err(|e| ErrorData::internal_error(e, None))?;
+                let response = context
+                    .peer
+                    .send_request(ServerRequest::CreateElicitationRequest(
+                        CreateElicitationRequest::new(CreateElicitationRequestParam {
+                            message,
+                            requested_schema: schema,
+                        }),
+                    ))
+                    .await
+                    .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+                let ClientResult::CreateElicitationResult(result) = response else {
+                    return Err(ErrorData::internal_error("unexpected response", None));
+                };
+                Ok(CallToolResult::structured(
+                    serde_json::to_value(result).expect("Result serializes"),
+                ))
+            }
+            "roots" => {
+                let result = context
+                    .peer
+                    .list_roots()
+                    .await
+                    .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+                let uris: Vec<_> = result.roots.into_iter().map(|r| r.uri).collect();
+                Ok(CallToolResult::structured(json!(uris)))
+            }
+            "sample" => {
+                let result = context
+                    .peer
+                    .create_message(CreateMessageRequestParam {
+                        messages: vec![SamplingMessage {
+                            role: Role::User,
+                            content: Content::text("ping"),
+                        }],
+                        model_preferences: None,
+                        system_prompt: None,
+                        include_context: None,
+                        temperature: None,
+                        max_tokens: 16,
+                        stop_sequences: None,
+                        metadata: None,
+                    })
+                    .await
+                    .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+                let text = result
+                    .message
+                    .content
+                    .as_text()
+                    .map(|t| t.text.clone())
+                    .unwrap_or_default();
+                Ok(CallToolResult::success(vec![Content::text(format!(
+                    "{}:{}",
+                    result.model, text
+                ))]))
+            }
+            other => Err(ErrorData::invalid_params(
+                format!("unknown tool {other}"),
+                None,
+            )),
+        }
+    }
+
+    async fn on_roots_list_changed(&self, _context: NotificationContext<RoleServer>) {
+        self.roots_changed.notify_one();
+    }
+}
+
+async fn connect(handler: KodegenClientHandler) -> KodegenConnection {
+    connect_to(CallbackServer::default(), handler).await
+}
+
+async fn connect_to(server: CallbackServer, handler: KodegenClientHandler) -> KodegenConnection {
+    let (server_io, client_io) = tokio::io::duplex(4096);
+    tokio::spawn(async move {
+        let server = server.serve(server_io).await?;
+        server.waiting().await?;
+        anyhow::Ok(())
+    });
+
+    let service = handler
+        .serve(client_io)
+        .await
+        .expect("Failed to initialize client");
+    KodegenConnection::from_service(service)
+}
+
+fn text_of(result: &CallToolResult) -> &str {
+    result.content[0]
+        .as_text()
+        .map(|t| t.text.as_str())
+        .expect("Expected text content")
+}
+
+/// Test that sampling is not advertised without a handler
+#[tokio::test]
+async fn test_sampling_not_advertised_by_default() {
+    let conn = connect(KodegenClientHandler::new("test-client")).await;
+
+    let result = conn
+        .client()
+        .call_tool("capabilities", json!({}))
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(
+        result.structured_content,
+        Some(json!({"sampling": false, "roots": false, "elicitation": false}))
+    );
+
+    // Without a handler the server's sampling request is rejected
+    assert!(conn.client().call_tool("sample", json!({})).await.is_err());
+}
+
+/// Test that a registered sampling handler is advertised and invoked
+#[tokio::test]
+async fn test_sampling_handler_answers_server() {
+    let handler = KodegenClientHandler::new("test-client").with_sampling_handler(
+        |params: CreateMessageRequestParam| async move {
+            let prompt = params.messages[0]
+                .content
+                .as_text()
+                .map(|t| t.text.clone())
+                .unwrap_or_default();
+            Ok(CreateMessageResult {
+                model: "test-model".to_string(),
+                stop_reason: Some(CreateMessageResult::STOP_REASON_END_TURN.to_string()),
+                message: SamplingMessage {
+                    role: Role::Assistant,
+                    content: Content::text(format!("{prompt}-pong")),
+                },
+            })
+        },
+    );
+    let conn = connect(handler).await;
+    let client = conn.client();
+
+    let result = client
+        .call_tool("capabilities", json!({}))
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(
+        result.structured_content,
+        Some(json!({"sampling": true, "roots": false, "elicitation": false}))
+    );
+
+    let result = client
+        .call_tool("sample", json!({}))
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(text_of(&result), "test-model:ping-pong");
+}
+
+/// Test that static roots are served and set_roots notifies the server
+#[tokio::test]
+async fn test_roots_static_and_set_roots() {
+    let server = CallbackServer::default();
+    let roots_changed = server.roots_changed.clone();
+    let handler =
+        KodegenClientHandler::new("test-client").with_roots(vec![path_root("/project", None)]);
+    let conn = connect_to(server, handler).await;
+    let client = conn.client();
+
+    let result = client
+        .call_tool("roots", json!({}))
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(result.structured_content, Some(json!(["file:///project/"])));
+
+    conn.set_roots(vec![Root {
+        uri: "file:///other/".to_string(),
+        name: Some("other".to_string()),
+    }])
+    .await
+    .expect("Failed to set roots");
+    tokio::time::timeout(Duration::from_secs(5), roots_changed.notified())
+        .await
+        .expect("Server never received roots/list_changed");
+
+    let result = client
+        .call_tool("roots", json!({}))
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(result.structured_content, Some(json!(["file:///other/"])));
+}
+
+/// Test that a callback roots provider is evaluated per request
+#[tokio::test]
+async fn test_roots_callback_provider() {
+    let handler = KodegenClientHandler::new("test-client")
+        .with_roots(|| async { Ok(vec![path_root("/from/callback", Some("cb"))]) });
+    let conn = connect(handler).await;
+
+    let result = conn
+        .client()
+        .call_tool("roots", json!({}))
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(
+        result.structured_content,
+        Some(json!(["file:///from/callback/"]))
+    );
+}
+
+/// Test that set_roots is rejected when the capability was not advertised
+#[tokio::test]
+async fn test_set_roots_requires_capability() {
+    let conn = connect(KodegenClientHandler::new("test-client")).await;
+
+    match conn.set_roots(vec![path_root("/project", None)]).await {
+        Err(ClientError::Protocol(msg)) => assert!(msg.contains("Roots capability")),
+        other => panic!("Expected Protocol error, got: {:?}", other),
+    }
+}
+
+/// Test that the fixture elicitation handler answers from its map
+#[tokio::test]
+async fn test_fixture_elicitation_handler() {
+    let fixtures = FixtureElicitationHandler::new()
+        .answer("Which branch?", json!({"branch": "main"}))
+        .otherwise(ElicitationResponse::Cancel);
+    let conn =
+        connect(KodegenClientHandler::new("test-client").with_elicitation_handler(fixtures)).await;
+    let client = conn.client();
+
+    let result = client
+        .call_tool("capabilities", json!({}))
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(
+        result.structured_content,
+        Some(json!({"sampling": false, "roots": false, "elicitation": true}))
+    );
+
+    let result = client
+        .call_tool("elicit", json!({"message": "Which branch?"}))
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(
+        result.structured_content,
+        Some(json!({"action": "accept", "content": {"branch": "main"}}))
+    );
+
+    let result = client
+        .call_tool("elicit", json!({"message": "Unknown question"}))
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(result.structured_content, Some(json!({"action": "cancel"})));
+}
+
+/// Test that a closure elicitation handler receives the requested schema
+#[tokio::test]
+async fn test_elicitation_closure_receives_schema() {
+    let handler = KodegenClientHandler::new("test-client").with_elicitation_handler(
+        |request: CreateElicitationRequestParam| async move {
+            let required = request.requested_schema.required.unwrap_or_default();
+            if required == ["branch"] {
+                Ok(ElicitationResponse::Accept(json!({"branch": "release"})))
+            } else {
+                Ok(ElicitationResponse::Decline)
+            }
+        },
+    );
+    let conn = connect(handler).await;
+
+    let result = conn
+        .client()
+        .call_tool("elicit", json!({"message": "Which branch?"}))
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(
+        result.structured_content,
+        Some(json!({"action": "accept", "content": {"branch": "release"}}))
+    );
+}
@@ -0,0 +1,178 @@
+// Integration tests for Rust type generation from tool schemas
+use kodegen_mcp_client::{KodegenClientHandler, KodegenConnection, KodegenTool, codegen};
+use rmcp::{
+    ErrorData, RoleServer, ServerHandler, ServiceExt,
+    model::{ListToolsResult, PaginatedRequestParam, ServerCapabilities, ServerInfo, Tool},
+    service::RequestContext,
+};
+use serde_json::json;
+
+/// Types generated from the snapshot, checked in so they are compiled here
+#[allow(dead_code)]
+mod generated {
+    include!("fixtures/codegen_expected.rs");
+}
+
+const SNAPSHOT: &str = include_str!("fixtures/codegen_snapshot.json");
+
+/// Test that the snapshot generates the checked-in source
+///
+/// Regenerate the fixture with:
+/// `cargo run --bin kodegen-codegen -- --snapshot tests/fixtures/codegen_snapshot.json --out tests/fixtures/codegen_expected.rs`
+#[test]
+fn test_snapshot_generates_expected_source() {
+    let tools = codegen::parse_snapshot(SNAPSHOT).expect("Failed to parse snapshot");
+    assert_eq!(
+        codegen::generate(&tools),
+        include_str!("fixtures/codegen_expected.rs")
+    );
+}
+
+/// Test that generated types serialize to what the schemas describe
+#[test]
+fn test_generated_types_round_trip() {
+    use generated::*;
+
+    assert_eq!(FsSearch::NAME, "fs_search");
+    let args = FsSearchArgs {
+        path: "/project".to_string(),
+        pattern: "*.rs".to_string(),
+        search_type: FsSearchArgsSearchType::Files,
+        r#type: None,
+        max_results: Some(5),
+    };
+    assert_eq!(
+        serde_json::to_value(&args).expect("Failed to serialize"),
+        json!({"path": "/project", "pattern": "*.rs", "searchType": "files", "maxResults": 5})
+    );
+
+    let output: FsSearchOutput =
+        serde_json::from_value(json!({"session_id": "s-1", "status": "running"}))
+            .expect("Failed to deserialize");
+    assert_eq!(output.status, Some(SearchStatus::Running));
+
+    let output: GithubListIssuesOutput = serde_json::from_value(json!({
+        "count": 1,
+        "issues": [{"number": 7, "title": "Bug", "user": {"login": "octocat"}}],
+    }))
+    .expect("Failed to deserialize");
+    assert_eq!(output.issues[0].user.login, "octocat");
+
+    assert_eq!(
+        serde_json::to_value(ConfigGetArgs {}).expect("Failed to serialize"),
+        json!({})
+    );
+}
+
+/// Test recursive `$defs` and properties sharing a `snake_case` name
+#[test]
+fn test_recursive_and_colliding_schemas() {
+    use generated::*;
+
+    let args = FsTreeArgs {
+        path: "/project".to_string(),
+        max_depth: Some(2),
+        max_depth_2: Some(3),
+    };
+    assert_eq!(
+        serde_json::to_value(&args).expect("Failed to serialize"),
+        json!({"path": "/project", "maxDepth": 2, "max_depth": 3})
+    );
+
+    let tree = json!({"root": {
+        "name": "src",
+        "children": [{"name": "lib.rs", "children": [], "parent": {"name": "src", "children": []}}],
+    }});
+    let output: FsTreeOutput = serde_json::from_value(tree.clone()).expect("Failed to deserialize");
+    let child = &output.root.children[0];
+    assert_eq!(child.parent.as_ref().map(|p| p.name.as_str()), Some("src"));
+    assert_eq!(
+        serde_json::to_value(&output).expect("Failed to serialize"),
+        tree
+    );
+
+    // A definition that only refers to itself falls back to untyped JSON
+    let mut tool = Tool::new("loop", "", std::sync::Arc::new(Default::default()));
+    let schema = json!({
+        "type": "object",
+        "properties": {"value": {"$ref": "#/$defs/Loop"}},
+        "$defs": {"Loop": {"$ref": "#/$defs/Loop"}}
+    });
+    tool.output_schema = Some(
+        schema
+            .as_object()
+            .cloned()
+            .expect("Schema is an object")
+            .into(),
+    );
+    let source = codegen::generate(&[tool]);
+    assert!(
+        source.contains("pub value: Option<serde_json::Value>"),
+        "{source}"
+    );
+}
+
+/// Test that snapshots round-trip and bare tool arrays are accepted
+#[test]
+fn test_snapshot_formats() {
+    let tools = codegen::parse_snapshot(SNAPSHOT).expect("Failed to parse snapshot");
+    let saved = codegen::to_snapshot(&tools).expect("Failed to write snapshot");
+    assert_eq!(
+        codegen::parse_snapshot(&saved).expect("Failed to reparse snapshot"),
+        tools
+    );
+
+    let array = serde_json::to_string(&tools).expect("Failed to serialize tools");
+    assert_eq!(
+        codegen::parse_snapshot(&array).expect("Failed to parse array"),
+        tools
+    );
+    assert!(codegen::parse_snapshot("{\"tools\": 1}").is_err());
+}
+
+/// In-process server listing the snapshot's tools
+struct SnapshotServer;
+
+impl ServerHandler for SnapshotServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            ..Default::default()
+        }
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, ErrorData> {
+        let tools: Vec<Tool> = codegen::parse_snapshot(SNAPSHOT)
+            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+            meta: None,
+        })
+    }
+}
+
+/// Test that generating from a live server matches the snapshot
+#[tokio::test]
+async fn test_generate_from_client() {
+    let (server_io, client_io) = tokio::io::duplex(16384);
+    tokio::spawn(async move {
+        let server = SnapshotServer.serve(server_io).await?;
+        server.waiting().await?;
+        anyhow::Ok(())
+    });
+    let service = KodegenClientHandler::new("test-client")
+        .serve(client_io)
+        .await
+        .expect("Failed to initialize client");
+    let conn = KodegenConnection::from_service(service);
+
+    let source = codegen::generate_from_client(&conn.client())
+        .await
+        .expect("Failed to generate");
+    assert_eq!(source, include_str!("fixtures/codegen_expected.rs"));
+}
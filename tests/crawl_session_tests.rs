@@ -0,0 +1,301 @@
+// Integration tests for following scrape_url crawls with CrawlSession
+use kodegen_mcp_client::{
+    CrawlSession, KodegenClientHandler, KodegenConnection, requests::StartCrawlArgs,
+    responses::citescrape::CrawlState, tools,
+};
+use rmcp::{
+    ErrorData, RoleServer, ServerHandler, ServiceExt,
+    model::{CallToolRequestParam, CallToolResult, ServerCapabilities, ServerInfo},
+    service::RequestContext,
+};
+use serde_json::{Value, json};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio_stream::StreamExt;
+
+#[derive(Default)]
+struct Crawl {
+    /// Fail instead of completing after the scripted pages
+    fail: bool,
+    /// Pages counted in `pages_crawled` but never returned
+    lost_pages: usize,
+    polls: usize,
+    pages: Vec<Value>,
+    cancelled: bool,
+    actions: Vec<Value>,
+}
+
+/// Pages crawled by each status poll; the crawl stops after the last one
+fn script(step: usize) -> Option<Vec<Value>> {
+    let page = |url: &str, depth: u32, status: u16| {
+        let title = format!("Title of {url}");
+        json!({"url": url, "title": title, "depth": depth, "status": status})
+    };
+    match step {
+        0 => Some(vec![page("https://example.com/", 0, 200)]),
+        1 => Some(vec![]),
+        2 => Some(vec![
+            page("https://example.com/a", 1, 200),
+            page("https://example.com/b", 1, 404),
+        ]),
+        _ => None,
+    }
+}
+
+/// In-process server running one scripted `scrape_url` crawl
+#[derive(Clone, Default)]
+struct CrawlServer {
+    crawl: Arc<Mutex<Crawl>>,
+}
+
+impl CrawlServer {
+    fn failing() -> Self {
+        let server = Self::default();
+        server.crawl.lock().expect("Lock poisoned").fail = true;
+        server
+    }
+}
+
+impl ServerHandler for CrawlServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            ..Default::default()
+        }
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        assert_eq!(request.name, tools::citescrape::SCRAPE_URL);
+        let args = json!(request.arguments);
+        let mut crawl = self.crawl.lock().expect("Lock poisoned");
+        let Some(action) = args["action"].as_str() else {
+            return Ok(CallToolResult::structured(json!({"crawl_id": "crawl-1"})));
+        };
+        assert_eq!(args["crawl_id"], "crawl-1");
+        crawl.actions.push(args.clone());
+        let output = match action {
+            "status" => {
+                let step = crawl.polls;
+                crawl.polls += 1;
+                let state = match script(step) {
+                    _ if crawl.cancelled => "cancelled",
+                    Some(pages) => {
+                        crawl.pages.extend(pages);
+                        "running"
+                    }
+                    None if crawl.fail => "failed",
+                    None => "completed",
+                };
+                let offset = args["offset"].as_u64().expect("offset") as usize;
+                json!({
+                    "crawl_id": "crawl-1",
+                    "state": state,
+                    "pages": &crawl.pages[offset..],
+                    "pages_crawled": crawl.pages.len() + crawl.lost_pages,
+                    "error": (state == "failed").then_some("Connection refused"),
+                })
+            }
+            "search" => json!({
+                "crawl_id": "crawl-1",
+                "count": 1,
+                "hits": [{
+                    "url": "https://example.com/a",
+                    "snippet": format!("... {} ...", args["query"].as_str().unwrap_or_default()),
+                    "score": 0.9,
+                }],
+            }),
+            "cancel" => {
+                let cancelled = !crawl.cancelled;
+                crawl.cancelled = true;
+                json!({"crawl_id": "crawl-1", "cancelled": cancelled})
+            }
+            other => panic!("Unexpected action {}", other),
+        };
+        Ok(CallToolResult::structured(output))
+    }
+}
+
+async fn start(server: CrawlServer) -> (KodegenConnection, CrawlSession) {
+    let (server_io, client_io) = tokio::io::duplex(16384);
+    tokio::spawn(async move {
+        let server = server.serve(server_io).await?;
+        server.waiting().await?;
+        anyhow::Ok(())
+    });
+    let service = KodegenClientHandler::new("test-client")
+        .serve(client_io)
+        .await
+        .expect("Failed to initialize client");
+    let conn = KodegenConnection::from_service(service);
+
+    let session = conn
+        .client()
+        .start_crawl(StartCrawlArgs::new("https://example.com/"))
+        .await
+        .expect("Failed to start crawl")
+        .poll_interval(Duration::from_millis(1));
+    (conn, session)
+}
+
+/// Test that pages stream once each with their metadata until the crawl completes
+#[tokio::test]
+async fn test_pages_stream() {
+    let server = CrawlServer::default();
+    let (_conn, mut session) = start(server.clone()).await;
+    assert_eq!(session.crawl_id(), "crawl-1");
+
+    let pages: Vec<_> = session
+        .pages()
+        .collect::<Result<_, _>>()
+        .await
+        .expect("Failed to stream pages");
+    let summary: Vec<_> = pages
+        .iter()
+        .map(|page| {
+            (
+                page.url.as_str(),
+                page.depth,
+                page.status,
+                page.is_success(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("https://example.com/", 0, 200, true),
+            ("https://example.com/a", 1, 200, true),
+            ("https://example.com/b", 1, 404, false),
+        ]
+    );
+    assert_eq!(
+        pages[0].title.as_deref(),
+        Some("Title of https://example.com/")
+    );
+    assert_eq!(session.state(), CrawlState::Completed);
+    assert!(session.is_finished());
+    assert_eq!(session.offset(), 3);
+
+    // Finished crawls are not cancelled on drop
+    drop(session);
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(!server.crawl.lock().expect("Lock poisoned").cancelled);
+}
+
+/// Test searching the crawled content and the arguments sent
+#[tokio::test]
+async fn test_status_and_search() {
+    let server = CrawlServer::default();
+    let (_conn, mut session) = start(server.clone()).await;
+
+    let status = session.status().await.expect("Failed to poll");
+    assert_eq!(status.state, CrawlState::Running);
+    assert_eq!(status.pages.len(), 1);
+    assert!(!session.is_finished());
+
+    let results = session.search("tokio").await.expect("Failed to search");
+    assert_eq!(results.hits[0].snippet, "... tokio ...");
+    assert_eq!(results.hits[0].score, Some(0.9));
+
+    let crawl = server.crawl.lock().expect("Lock poisoned");
+    assert_eq!(
+        crawl.actions,
+        [
+            json!({"action": "status", "crawl_id": "crawl-1", "offset": 0}),
+            json!({"action": "search", "crawl_id": "crawl-1", "query": "tokio"}),
+        ]
+    );
+}
+
+/// Test that cancelled and failed crawls end with their state and error
+#[tokio::test]
+async fn test_cancel_and_failure() {
+    let server = CrawlServer::default();
+    let (_conn, mut session) = start(server.clone()).await;
+    session.status().await.expect("Failed to poll");
+    let cancelled = session.cancel().await.expect("Failed to cancel");
+    assert!(cancelled.cancelled);
+    assert_eq!(session.state(), CrawlState::Cancelled);
+    assert!(session.is_finished());
+    assert_eq!(
+        session.wait().await.expect("Failed to wait"),
+        CrawlState::Cancelled
+    );
+    // Waiting on a cancelled crawl does not poll it again
+    assert_eq!(server.crawl.lock().expect("Lock poisoned").polls, 1);
+
+    let (_conn, mut session) = start(CrawlServer::failing()).await;
+    assert_eq!(
+        session.wait().await.expect("Failed to wait"),
+        CrawlState::Failed
+    );
+    assert_eq!(session.error(), Some("Connection refused"));
+}
+
+/// Test that finished crawls counting pages they never return still end
+#[tokio::test]
+async fn test_finished_crawl_with_lost_pages() {
+    let server = CrawlServer::default();
+    server.crawl.lock().expect("Lock poisoned").lost_pages = 2;
+    let (_conn, mut session) = start(server.clone()).await;
+
+    let urls: Vec<String> = tokio::time::timeout(
+        Duration::from_secs(5),
+        session
+            .pages()
+            .map(|page| page.map(|page| page.url))
+            .collect::<Result<Vec<_>, _>>(),
+    )
+    .await
+    .expect("Pages stream did not end")
+    .expect("Failed to stream pages");
+    assert_eq!(urls.len(), 3);
+    assert!(session.is_finished());
+    assert_eq!(
+        session.wait().await.expect("Failed to wait"),
+        CrawlState::Completed
+    );
+}
+
+/// Test that dropping a running crawl cancels it on the server
+#[tokio::test]
+async fn test_drop_cancels_crawl() {
+    let server = CrawlServer::default();
+    let (_conn, mut session) = start(server.clone()).await;
+    session.status().await.expect("Failed to poll");
+    drop(session);
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !server.crawl.lock().expect("Lock poisoned").cancelled {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("Crawl was not cancelled");
+}
+
+/// Test that closing a running crawl cancels it before returning, exactly once
+#[tokio::test]
+async fn test_close_cancels_crawl() {
+    let server = CrawlServer::default();
+    let (_conn, mut session) = start(server.clone()).await;
+    session.status().await.expect("Failed to poll");
+    session.close().await.expect("Failed to close");
+    assert!(server.crawl.lock().expect("Lock poisoned").cancelled);
+
+    // Give a (wrongly) spawned drop-cancellation the chance to run
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    let crawl = server.crawl.lock().expect("Lock poisoned");
+    let cancels = crawl
+        .actions
+        .iter()
+        .filter(|args| args["action"] == "cancel")
+        .count();
+    assert_eq!(cancels, 1);
+}
@@ -0,0 +1,211 @@
+// Integration tests for the typed database tool responses and row decoding
+use kodegen_mcp_client::{
+    ClientError, KodegenClientHandler, KodegenConnection,
+    responses::database::{ExecuteSqlResponse, PoolStatsResponse, SqlType, TableSchemaResponse},
+    tools,
+    validation::Validate,
+};
+use rmcp::{
+    ErrorData, RoleServer, ServerHandler, ServiceExt,
+    model::{CallToolRequestParam, CallToolResult, ServerCapabilities, ServerInfo},
+    service::RequestContext,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct User {
+    id: i64,
+    email: String,
+    #[serde(default)]
+    nickname: Option<String>,
+}
+
+/// Test that array and object rows decode by column name
+#[test]
+fn test_rows_decode_by_column_name() {
+    let response: ExecuteSqlResponse = serde_json::from_value(json!({
+        "columns": [
+            {"name": "email", "data_type": "varchar(255)"},
+            {"name": "id", "data_type": "int8"},
+            {"name": "active", "data_type": "boolean"},
+        ],
+        "rows": [
+            ["ada@example.com", 1, true],
+            {"id": 2, "email": "grace@example.com", "nickname": "amazing"},
+        ],
+        "row_count": 2,
+    }))
+    .expect("Failed to deserialize");
+    assert_eq!(response.validate(), Ok(()));
+    assert_eq!(
+        response.column_names().collect::<Vec<_>>(),
+        ["email", "id", "active"]
+    );
+    assert_eq!(response.columns[1].sql_type(), Some(SqlType::Integer));
+
+    let users: Vec<User> = response.rows_as().expect("Failed to decode rows");
+    assert_eq!(
+        users,
+        [
+            User {
+                id: 1,
+                email: "ada@example.com".to_string(),
+                nickname: None,
+            },
+            User {
+                id: 2,
+                email: "grace@example.com".to_string(),
+                nickname: Some("amazing".to_string()),
+            },
+        ]
+    );
+
+    let error = response
+        .rows_as::<(i64, String)>()
+        .expect_err("Rows should not decode to a tuple");
+    assert!(error.to_string().starts_with("row 0: "));
+}
+
+/// Test that malformed result sets fail validation
+#[test]
+fn test_result_shape_validation() {
+    let response: ExecuteSqlResponse = serde_json::from_value(json!({
+        "columns": [{"name": "id"}, {"name": ""}],
+        "rows": [[1, 2], [3], "oops"],
+        "row_count": 2,
+    }))
+    .expect("Failed to deserialize");
+    let errors = response.validate().expect_err("Result should be invalid");
+    assert_eq!(
+        errors.to_string(),
+        "/columns/1/name: name cannot be empty; \
+         /rows: row_count field value (2) does not match actual length (3); \
+         /rows/1: row has 1 values but there are 2 columns; \
+         /rows/2: row must be an array or an object"
+    );
+
+    let update: ExecuteSqlResponse =
+        serde_json::from_value(json!({"rows_affected": 3})).expect("Failed to deserialize");
+    assert_eq!(update.validate(), Ok(()));
+    assert_eq!(update.rows_affected, Some(3));
+    assert!(update.rows_as::<User>().expect("No rows").is_empty());
+}
+
+/// Test table schemas, SQL type families and pool statistics
+#[test]
+fn test_table_schema_and_pool_stats() {
+    let schema: TableSchemaResponse = serde_json::from_value(json!({
+        "table": "users",
+        "schema": "public",
+        "columns": [
+            {"name": "id", "data_type": "bigint", "primary_key": true},
+            {"name": "email", "data_type": "character varying(255)"},
+            {"name": "created_at", "data_type": "timestamp with time zone", "nullable": true},
+            {"name": "tags", "data_type": "text[]", "nullable": true},
+        ],
+    }))
+    .expect("Failed to deserialize");
+    assert_eq!(schema.validate(), Ok(()));
+    let types: Vec<_> = schema.columns.iter().map(|c| c.sql_type()).collect();
+    assert_eq!(
+        types,
+        [
+            SqlType::Integer,
+            SqlType::Text,
+            SqlType::Timestamp,
+            SqlType::Array
+        ]
+    );
+    assert_eq!(
+        schema
+            .primary_key()
+            .map(|c| c.name.as_str())
+            .collect::<Vec<_>>(),
+        ["id"]
+    );
+    assert!(schema.column("created_at").is_some_and(|c| c.nullable));
+    assert_eq!(SqlType::from_name("NUMERIC(10, 2)"), SqlType::Decimal);
+    assert_eq!(SqlType::from_name("geometry"), SqlType::Other);
+
+    let stats: PoolStatsResponse = serde_json::from_value(json!({
+        "size": 4,
+        "idle": 1,
+        "max_size": 10,
+    }))
+    .expect("Failed to deserialize");
+    assert_eq!(stats.validate(), Ok(()));
+    assert_eq!(stats.in_use(), 3);
+
+    let stats: PoolStatsResponse =
+        serde_json::from_value(json!({"size": 12, "idle": 13, "max_size": 10}))
+            .expect("Failed to deserialize");
+    assert_eq!(
+        stats.validate().map_err(|e| e.to_string()),
+        Err("/idle: idle value (13) is greater than maximum (12); \
+             /size: size value (12) is greater than maximum (10)"
+            .to_string())
+    );
+}
+
+/// In-process server answering every `db_execute_sql` call with two rows
+struct SqlServer;
+
+impl ServerHandler for SqlServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            ..Default::default()
+        }
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        assert_eq!(request.name, tools::database::EXECUTE_SQL);
+        let query = json!(request.arguments)["query"].clone();
+        Ok(CallToolResult::structured(json!({
+            "columns": [{"name": "id"}, {"name": "email"}, {"name": "query"}],
+            "rows": [[1, "ada@example.com", query], [2, null, query]],
+        })))
+    }
+}
+
+/// Test that query_as runs the SQL and decodes rows, reporting bad rows
+#[tokio::test]
+async fn test_query_as() {
+    let (server_io, client_io) = tokio::io::duplex(4096);
+    tokio::spawn(async move {
+        let server = SqlServer.serve(server_io).await?;
+        server.waiting().await?;
+        anyhow::Ok(())
+    });
+    let service = KodegenClientHandler::new("test-client")
+        .serve(client_io)
+        .await
+        .expect("Failed to initialize client");
+    let conn = KodegenConnection::from_service(service);
+    let client = conn.client();
+
+    #[derive(Deserialize)]
+    struct Echo {
+        id: u32,
+        query: String,
+    }
+    let rows: Vec<Echo> = client
+        .query_as("SELECT id FROM users")
+        .await
+        .expect("Failed to query");
+    assert_eq!(rows.iter().map(|r| r.id).collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(rows[0].query, "SELECT id FROM users");
+
+    match client.query_as::<User>("SELECT * FROM users").await {
+        Err(ClientError::ParseError { tool_name, source }) => {
+            assert_eq!(tool_name, "db_execute_sql");
+            assert!(source.to_string().starts_with("row 1: "));
+        }
+        other => panic!("Expected ParseError, got: {:?}", other),
+    }
+}
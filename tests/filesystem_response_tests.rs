@@ -0,0 +1,176 @@
+// Integration tests for the typed filesystem tool responses
+use kodegen_mcp_client::{
+    requests::WriteFileArgs,
+    responses::filesystem::{
+        DiffLine, EditBlockResponse, EntryKind, FileInfoResponse, ListDirectoryResponse,
+        ReadFileResponse, SearchResultsResponse, WriteMode,
+    },
+    validation::Validate,
+};
+use serde_json::json;
+
+fn read(start_line: usize, end_line: usize, total_lines: usize) -> ReadFileResponse {
+    serde_json::from_value(json!({
+        "path": "/src/lib.rs",
+        "content": "",
+        "start_line": start_line,
+        "end_line": end_line,
+        "total_lines": total_lines,
+    }))
+    .expect("Failed to deserialize")
+}
+
+/// Test that read windows report how to continue past the line limit
+#[test]
+fn test_read_file_windows() {
+    let first = read(1, 1000, 2500);
+    assert_eq!(first.validate(), Ok(()));
+    assert_eq!(first.lines_returned(), 1000);
+    assert!(!first.is_complete());
+    assert!(first.within_line_limit(1000));
+    assert!(!first.within_line_limit(999));
+    assert_eq!(first.next_offset(), Some(1000));
+
+    let last = read(2001, 2500, 2500);
+    assert_eq!(last.next_offset(), None);
+
+    let whole = read(1, 12, 12);
+    assert!(whole.is_complete());
+
+    let empty = read(1, 0, 0);
+    assert_eq!(empty.validate(), Ok(()));
+    assert_eq!(empty.lines_returned(), 0);
+    assert!(empty.is_complete());
+
+    // Line numbers at the top of the range don't overflow
+    let huge = read(usize::MAX, usize::MAX, usize::MAX);
+    assert_eq!(huge.validate(), Ok(()));
+    assert_eq!(huge.lines_returned(), 0);
+}
+
+/// Test that inconsistent read windows fail validation
+#[test]
+fn test_read_file_validation() {
+    let errors = read(0, 30, 20)
+        .validate()
+        .expect_err("Window should be invalid");
+    assert_eq!(
+        errors.to_string(),
+        "/start_line: start_line value (0) is less than minimum (1); \
+         /end_line: end_line value (30) is greater than maximum (20)"
+    );
+
+    let errors = read(10, 5, 20)
+        .validate()
+        .expect_err("Window should be invalid");
+    assert_eq!(
+        errors.to_string(),
+        "/end_line: end_line value (5) is less than minimum (9)"
+    );
+}
+
+/// Test that long writes are split under the write line limit
+#[test]
+fn test_chunked_writes() {
+    let chunks = WriteFileArgs::chunked("/out.txt", "a\nb\nc\nd\ne", 2);
+    let parts: Vec<_> = chunks
+        .iter()
+        .map(|c| (c.content.as_str(), c.mode))
+        .collect();
+    assert_eq!(
+        parts,
+        [
+            ("a\nb\n", WriteMode::Rewrite),
+            ("c\nd\n", WriteMode::Append),
+            ("e", WriteMode::Append),
+        ]
+    );
+    assert!(chunks.iter().all(|c| c.path == "/out.txt"));
+
+    let empty = WriteFileArgs::chunked("/out.txt", "", 2);
+    assert_eq!(empty.len(), 1);
+    assert_eq!(empty[0].mode, WriteMode::Rewrite);
+    assert_eq!(
+        serde_json::to_value(&empty[0]).expect("Failed to serialize"),
+        json!({"path": "/out.txt", "content": "", "mode": "rewrite"})
+    );
+}
+
+/// Test directory listings, file info and their invariants
+#[test]
+fn test_listing_and_info() {
+    let listing: ListDirectoryResponse = serde_json::from_value(json!({
+        "path": "/src",
+        "count": 3,
+        "entries": [
+            {"name": "lib.rs", "path": "/src/lib.rs", "kind": "file", "size": 120},
+            {"name": "bin", "path": "/src/bin", "kind": "directory"},
+            {"name": "", "path": "/src/dev", "kind": "device"},
+        ],
+    }))
+    .expect("Failed to deserialize");
+    assert_eq!(listing.files().count(), 1);
+    assert_eq!(listing.directories().count(), 1);
+    assert_eq!(listing.entries[2].kind, EntryKind::Other);
+    let errors = listing.validate().expect_err("Listing should be invalid");
+    assert_eq!(errors.to_string(), "/entries/2/name: name cannot be empty");
+
+    let info: FileInfoResponse = serde_json::from_value(json!({
+        "path": "/src/lib.rs",
+        "kind": "file",
+        "size": 120,
+        "permissions": "644",
+        "line_count": 7,
+    }))
+    .expect("Failed to deserialize");
+    assert_eq!(info.kind, EntryKind::File);
+    assert_eq!(info.line_count, Some(7));
+    assert!(!info.readonly);
+}
+
+/// Test edit diffs and search result pages
+#[test]
+fn test_edit_diff_and_search_results() {
+    let edit: EditBlockResponse = serde_json::from_value(json!({
+        "path": "/src/lib.rs",
+        "replacements": 1,
+        "diff": "--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,2 +1,2 @@\n fn main() {\n-    old();\n+    new();\n",
+    }))
+    .expect("Failed to deserialize");
+    assert_eq!(
+        edit.changed_lines(),
+        [
+            DiffLine::Removed("    old();"),
+            DiffLine::Added("    new();")
+        ]
+    );
+
+    // Content lines that look like file headers are still changes
+    let edit: EditBlockResponse = serde_json::from_value(json!({
+        "path": "/notes.md",
+        "replacements": 1,
+        "diff": "--- a/notes.md\n+++ b/notes.md\n@@ -1 +1 @@\n--- old rule\n+++ new rule\n",
+    }))
+    .expect("Failed to deserialize");
+    assert_eq!(
+        edit.changed_lines(),
+        [
+            DiffLine::Removed("-- old rule"),
+            DiffLine::Added("++ new rule")
+        ]
+    );
+
+    let page: SearchResultsResponse = serde_json::from_value(json!({
+        "sessionId": "search-1",
+        "results": [
+            {"file": "/src/lib.rs", "line": 3, "match": "fn main"},
+            {"file": "/src/bin/tool.rs"},
+        ],
+        "total_results": 2,
+        "is_complete": true,
+    }))
+    .expect("Failed to deserialize");
+    assert_eq!(page.validate(), Ok(()));
+    assert_eq!(page.results[0].matched.as_deref(), Some("fn main"));
+    assert_eq!(page.results[1].line, None);
+}
@@ -0,0 +1,165 @@
+// Generated by kodegen-codegen from tool schemas. Do not edit by hand.
+
+#[allow(unused_imports)]
+use kodegen_mcp_client::{KodegenTool, validation::Validate};
+use serde::{Deserialize, Serialize};
+
+/// Read a file from disk.
+#[derive(Debug, Clone, Copy)]
+pub struct FsReadFile;
+
+impl KodegenTool for FsReadFile {
+    const NAME: &'static str = "fs_read_file";
+    type Args = FsReadFileArgs;
+    type Output = serde_json::Value;
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FsReadFileArgs {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<u64>,
+    /// First line; negative counts from the end
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+    /// Absolute path of the file
+    pub path: String,
+}
+
+/// Start a background search.
+///
+/// Returns a session to poll.
+#[derive(Debug, Clone, Copy)]
+pub struct FsSearch;
+
+impl KodegenTool for FsSearch {
+    const NAME: &'static str = "fs_search";
+    type Args = FsSearchArgs;
+    type Output = FsSearchOutput;
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FsSearchArgs {
+    #[serde(rename = "maxResults", default, skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<u64>,
+    pub path: String,
+    pub pattern: String,
+    #[serde(rename = "searchType")]
+    pub search_type: FsSearchArgsSearchType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FsSearchArgsSearchType {
+    #[serde(rename = "files")]
+    Files,
+    #[serde(rename = "content")]
+    Content,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+pub struct FsSearchOutput {
+    pub session_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<SearchStatus>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SearchStatus {
+    #[serde(rename = "running")]
+    Running,
+    #[serde(rename = "completed")]
+    Completed,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GithubListIssues;
+
+impl KodegenTool for GithubListIssues {
+    const NAME: &'static str = "github_list_issues";
+    type Args = GithubListIssuesArgs;
+    type Output = GithubListIssuesOutput;
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GithubListIssuesArgs {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
+    pub owner: String,
+    pub repo: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<GithubListIssuesArgsState>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GithubListIssuesArgsState {
+    #[serde(rename = "open")]
+    Open,
+    #[serde(rename = "closed")]
+    Closed,
+    #[serde(rename = "all")]
+    All,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+pub struct GithubListIssuesOutput {
+    pub count: u64,
+    pub issues: Vec<GithubListIssuesOutputIssuesItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+pub struct GithubListIssuesOutputIssuesItem {
+    pub number: i64,
+    pub title: String,
+    pub user: GithubListIssuesOutputIssuesItemUser,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+pub struct GithubListIssuesOutputIssuesItemUser {
+    pub login: String,
+}
+
+/// Read the server configuration
+#[derive(Debug, Clone, Copy)]
+pub struct ConfigGet;
+
+impl KodegenTool for ConfigGet {
+    const NAME: &'static str = "config_get";
+    type Args = ConfigGetArgs;
+    type Output = serde_json::Value;
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigGetArgs {}
+
+/// List a directory tree.
+#[derive(Debug, Clone, Copy)]
+pub struct FsTree;
+
+impl KodegenTool for FsTree {
+    const NAME: &'static str = "fs_tree";
+    type Args = FsTreeArgs;
+    type Output = FsTreeOutput;
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FsTreeArgs {
+    #[serde(rename = "maxDepth", default, skip_serializing_if = "Option::is_none")]
+    pub max_depth: Option<u64>,
+    #[serde(rename = "max_depth", default, skip_serializing_if = "Option::is_none")]
+    pub max_depth_2: Option<u64>,
+    pub path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+pub struct FsTreeOutput {
+    pub root: Node,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+pub struct Node {
+    pub children: Vec<Node>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<Node>>,
+}
@@ -0,0 +1,186 @@
+// Integration tests for the typed git tool responses
+use kodegen_mcp_client::{
+    KodegenTool,
+    requests::{GitLog, GitLogArgs, GitPull, GitShow, GitSyncArgs},
+    responses::git::{
+        DiffLineKind, FileStatus, GitBranchListResponse, GitDiffResponse, GitLogResponse,
+        GitMergeResponse, GitStatusResponse, GitWorktreeListResponse,
+    },
+    validation::Validate,
+};
+use serde_json::json;
+
+/// Test that status entries split into staged, unstaged and conflicted paths
+#[test]
+fn test_status_entries() {
+    let status: GitStatusResponse = serde_json::from_value(json!({
+        "branch": "main",
+        "upstream": "origin/main",
+        "ahead": 2,
+        "entries": [
+            {"path": "src/lib.rs", "status": "modified", "staged": true},
+            {"path": "src/new.rs", "status": "renamed", "staged": true, "original_path": "src/old.rs"},
+            {"path": "notes.txt", "status": "untracked"},
+            {"path": "Cargo.lock", "status": "conflicted"},
+            {"path": "link", "status": "whiteout"},
+        ],
+    }))
+    .expect("Failed to deserialize");
+    assert_eq!(status.validate(), Ok(()));
+    assert!(!status.is_clean());
+    assert_eq!(status.ahead, 2);
+    assert_eq!(status.behind, 0);
+    assert_eq!(status.staged().count(), 2);
+    assert_eq!(status.unstaged().count(), 3);
+    assert_eq!(
+        status.entries[1].original_path.as_deref(),
+        Some("src/old.rs")
+    );
+    assert_eq!(status.entries[4].status, FileStatus::Other);
+    let conflicts: Vec<_> = status.conflicts().map(|e| e.path.as_str()).collect();
+    assert_eq!(conflicts, ["Cargo.lock"]);
+
+    let clean: GitStatusResponse =
+        serde_json::from_value(json!({"entries": []})).expect("Failed to deserialize");
+    assert!(clean.is_clean());
+    assert_eq!(clean.branch, None);
+}
+
+/// Test that commit logs are checked for IDs, signatures and counts
+#[test]
+fn test_commit_log_validation() {
+    let commit = |sha: &str, name: &str, time: i64| {
+        json!({
+            "sha": sha,
+            "message": "Add typed responses\n\nDetails",
+            "author": {"name": name, "email": "ada@example.com", "time": time},
+            "parents": ["2ef535a", "bdb8bad"],
+        })
+    };
+    let log: GitLogResponse = serde_json::from_value(json!({
+        "count": 1,
+        "commits": [commit("e02e067", "Ada", 1_700_000_000)],
+    }))
+    .expect("Failed to deserialize");
+    assert_eq!(log.validate(), Ok(()));
+    assert_eq!(log.commits[0].summary(), "Add typed responses");
+    assert!(log.commits[0].is_merge());
+
+    // Every broken commit is reported, not just the first
+    let log: GitLogResponse = serde_json::from_value(json!({
+        "count": 3,
+        "commits": [commit("", "Ada", 1_700_000_000), commit("e02e067", "", 0)],
+    }))
+    .expect("Failed to deserialize");
+    let errors = log.validate().expect_err("Log should be invalid");
+    assert_eq!(
+        errors.to_string(),
+        "/commits: count field value (3) does not match actual length (2); \
+         /commits/0/sha: sha cannot be empty; \
+         /commits/1/author/name: name cannot be empty; \
+         /commits/1/author/time: time value (0) is less than minimum (1)"
+    );
+}
+
+/// Test that diff hunks expose added and deleted lines
+#[test]
+fn test_diff_hunks() {
+    let diff: GitDiffResponse = serde_json::from_value(json!({
+        "files": [{
+            "path": "src/lib.rs",
+            "status": "modified",
+            "insertions": 1,
+            "deletions": 1,
+            "hunks": [{
+                "old_start": 10,
+                "old_lines": 2,
+                "new_start": 10,
+                "new_lines": 2,
+                "header": "@@ -10,2 +10,2 @@",
+                "lines": [
+                    {"kind": "context", "content": "fn main() {", "old_line": 10, "new_line": 10},
+                    {"kind": "deletion", "content": "    old();", "old_line": 11},
+                    {"kind": "addition", "content": "    new();", "new_line": 11},
+                ],
+            }],
+        }],
+    }))
+    .expect("Failed to deserialize");
+    assert_eq!(diff.validate(), Ok(()));
+    let hunk = &diff.files[0].hunks[0];
+    assert_eq!(hunk.additions().collect::<Vec<_>>(), ["    new();"]);
+    assert_eq!(hunk.deletions().collect::<Vec<_>>(), ["    old();"]);
+    assert_eq!(hunk.lines[0].kind, DiffLineKind::Context);
+    assert_eq!(hunk.lines[1].new_line, None);
+}
+
+/// Test branch and worktree listings and merge outcomes
+#[test]
+fn test_branches_worktrees_and_merges() {
+    let branches: GitBranchListResponse = serde_json::from_value(json!({
+        "count": 2,
+        "branches": [
+            {"name": "main", "current": true, "commit": "e02e067"},
+            {"name": "origin/main", "remote": true},
+        ],
+    }))
+    .expect("Failed to deserialize");
+    assert_eq!(branches.validate(), Ok(()));
+    assert_eq!(branches.current().map(|b| b.name.as_str()), Some("main"));
+    assert!(branches.branches[1].remote);
+
+    let worktrees: GitWorktreeListResponse = serde_json::from_value(json!({
+        "count": 3,
+        "worktrees": [
+            {"path": "/repo", "branch": "main", "main": true},
+            {"path": "/repo-release", "head": "e02e067", "locked": true, "lock_reason": "release"},
+        ],
+    }))
+    .expect("Failed to deserialize");
+    assert!(worktrees.worktrees[0].main);
+    assert_eq!(
+        worktrees.worktrees[1].lock_reason.as_deref(),
+        Some("release")
+    );
+    let errors = worktrees
+        .validate()
+        .expect_err("Worktrees should be invalid");
+    assert_eq!(
+        errors.to_string(),
+        "/worktrees: count field value (3) does not match actual length (2)"
+    );
+
+    let merge: GitMergeResponse = serde_json::from_value(json!({
+        "fast_forward": false,
+        "conflicts": ["Cargo.toml"],
+    }))
+    .expect("Failed to deserialize");
+    assert_eq!(merge.validate(), Ok(()));
+    assert!(merge.has_conflicts());
+    assert_eq!(merge.commit, None);
+}
+
+/// Test that git arguments serialize with only the fields that were set
+#[test]
+fn test_git_args() {
+    assert_eq!(GitLog::NAME, "git_log");
+    let args = GitLogArgs {
+        max_count: Some(10),
+        ..GitLogArgs::new("/repo")
+    };
+    assert_eq!(
+        serde_json::to_value(&args).expect("Failed to serialize"),
+        json!({"path": "/repo", "max_count": 10})
+    );
+
+    assert_eq!(GitShow::NAME, "git_show");
+    assert_eq!(GitPull::NAME, "git_pull");
+    let args = GitSyncArgs {
+        remote: Some("origin".to_string()),
+        ..GitSyncArgs::new("/repo")
+    };
+    assert_eq!(
+        serde_json::to_value(&args).expect("Failed to serialize"),
+        json!({"path": "/repo", "remote": "origin"})
+    );
+}
@@ -0,0 +1,139 @@
+// Integration tests for typed tool invocation through KodegenTool
+use kodegen_mcp_client::{
+    ClientError, KodegenClientHandler, KodegenConnection, KodegenTool,
+    requests::{
+        ListIssues, ListIssuesArgs, ListSchemas, ListSchemasArgs, SearchType, StartSearch,
+        StartSearchArgs,
+    },
+    tools,
+};
+use rmcp::{
+    ErrorData, RoleServer, ServerHandler, ServiceExt,
+    model::{CallToolRequestParam, CallToolResult, ServerCapabilities, ServerInfo},
+    service::RequestContext,
+};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+/// In-process server recording every call's name and arguments
+///
+/// Searches return a session ID, issue listings an inconsistent count and
+/// schema listings one schema; every other tool echoes its arguments.
+#[derive(Clone, Default)]
+struct RecordingServer {
+    calls: Arc<Mutex<Vec<(String, serde_json::Value)>>>,
+}
+
+impl ServerHandler for RecordingServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            ..Default::default()
+        }
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let arguments = json!(request.arguments);
+        self.calls
+            .lock()
+            .expect("Lock poisoned")
+            .push((request.name.to_string(), arguments.clone()));
+
+        let output = match request.name.as_ref() {
+            name if name == tools::filesystem::SEARCH => json!({"session_id": "search-1"}),
+            name if name == tools::github::LIST_ISSUES => json!({"count": 2, "issues": []}),
+            name if name == tools::database::LIST_SCHEMAS => json!({"schemas": ["public"]}),
+            _ => arguments,
+        };
+        Ok(CallToolResult::structured(output))
+    }
+}
+
+async fn connect(server: RecordingServer) -> KodegenConnection {
+    let (server_io, client_io) = tokio::io::duplex(4096);
+    tokio::spawn(async move {
+        let server = server.serve(server_io).await?;
+        server.waiting().await?;
+        anyhow::Ok(())
+    });
+
+    let service = KodegenClientHandler::new("test-client")
+        .serve(client_io)
+        .await
+        .expect("Failed to initialize client");
+    KodegenConnection::from_service(service)
+}
+
+/// Test that typed arguments are sent under the tool's name and field names
+#[tokio::test]
+async fn test_invoke_sends_typed_arguments() {
+    let server = RecordingServer::default();
+    let conn = connect(server.clone()).await;
+
+    let args = StartSearchArgs {
+        max_results: Some(10),
+        ..StartSearchArgs::new("/project", "*.rs", SearchType::Files)
+    };
+    let response = conn
+        .client()
+        .invoke::<StartSearch>(args)
+        .await
+        .expect("Failed to invoke tool");
+    assert_eq!(response.session_id, "search-1");
+
+    let calls = server.calls.lock().expect("Lock poisoned");
+    assert_eq!(
+        *calls,
+        [(
+            StartSearch::NAME.to_string(),
+            json!({
+                "path": "/project",
+                "pattern": "*.rs",
+                "searchType": "files",
+                "maxResults": 10,
+            })
+        )]
+    );
+}
+
+/// Test that argument structs without fields are sent as an empty object
+#[tokio::test]
+async fn test_invoke_empty_arguments() {
+    let server = RecordingServer::default();
+    let conn = connect(server.clone()).await;
+
+    let response = conn
+        .client()
+        .invoke::<ListSchemas>(ListSchemasArgs {})
+        .await
+        .expect("Failed to invoke tool");
+    assert_eq!(response.schemas, ["public"]);
+
+    let calls = server.calls.lock().expect("Lock poisoned");
+    assert_eq!(*calls, [(ListSchemas::NAME.to_string(), json!({}))]);
+}
+
+/// Test that invoked responses are validated
+#[tokio::test]
+async fn test_invoke_validates_response() {
+    let conn = connect(RecordingServer::default()).await;
+
+    match conn
+        .client()
+        .invoke::<ListIssues>(ListIssuesArgs::new("myorg", "myrepo"))
+        .await
+    {
+        Err(ClientError::ValidationError { tool_name, errors }) => {
+            assert_eq!(tool_name, "github_list_issues");
+            assert_eq!(
+                errors.to_string(),
+                "/issues: count field value (2) does not match actual length (0)"
+            );
+        }
+        other => panic!("Expected ValidationError, got: {:?}", other),
+    }
+}
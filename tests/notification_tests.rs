@@ -0,0 +1,237 @@
+// Integration tests for the server notification stream
+use kodegen_mcp_client::{
+    ClientError, KodegenClientHandler, KodegenConnection, ServerNotification, ToolProgress,
+};
+use rmcp::{
+    ErrorData, RoleServer, ServerHandler, ServiceExt,
+    model::{
+        CallToolRequestParam, CallToolResult, ListToolsResult, LoggingLevel,
+        LoggingMessageNotificationParam, NumberOrString, PaginatedRequestParam,
+        ProgressNotificationParam, ProgressToken, ResourceUpdatedNotificationParam,
+        ServerCapabilities, ServerInfo,
+    },
+    service::RequestContext,
+};
+use serde_json::json;
+use std::{sync::Arc, time::Duration};
+use tokio_stream::StreamExt;
+
+/// In-process server whose "notify" tool emits one of each notification kind
+///
+/// "steps" reports progress every 50ms for four steps; "stall" never reports.
+struct NotifyingServer;
+
+impl ServerHandler for NotifyingServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_tool_list_changed()
+                .enable_logging()
+                .build(),
+            ..Default::default()
+        }
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, ErrorData> {
+        Ok(ListToolsResult::default())
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let send_error =
+            |e: rmcp::service::ServiceError| ErrorData::internal_error(e.to_string(), None);
+        let peer = &context.peer;
+
+        match request.name.as_ref() {
+            "steps" => {
+                let token = context
+                    .meta
+                    .get_progress_token()
+                    .ok_or_else(|| ErrorData::invalid_params("missing progress token", None))?;
+                for step in 1..=4 {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    peer.notify_progress(ProgressNotificationParam {
+                        progress_token: token.clone(),
+                        progress: f64::from(step),
+                        total: Some(4.0),
+                        message: Some(format!("step {step}")),
+                    })
+                    .await
+                    .map_err(send_error)?;
+                }
+                return Ok(CallToolResult::structured(json!({"steps": 4})));
+            }
+            "stall" => {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                return Ok(CallToolResult::structured(json!({})));
+            }
+            _ => {}
+        }
+
+        peer.notify_tool_list_changed().await.map_err(send_error)?;
+        peer.notify_logging_message(LoggingMessageNotificationParam {
+            level: LoggingLevel::Warning,
+            logger: Some("indexer".to_string()),
+            data: json!("disk almost full"),
+        })
+        .await
+        .map_err(send_error)?;
+        peer.notify_resource_updated(ResourceUpdatedNotificationParam {
+            uri: "file:///schema.sql".to_string(),
+        })
+        .await
+        .map_err(send_error)?;
+        peer.notify_progress(ProgressNotificationParam {
+            progress_token: ProgressToken(NumberOrString::String(Arc::from("job-1"))),
+            progress: 1.0,
+            total: Some(2.0),
+            message: Some("halfway".to_string()),
+        })
+        .await
+        .map_err(send_error)?;
+        peer.notify_prompt_list_changed()
+            .await
+            .map_err(send_error)?;
+
+        Ok(CallToolResult::structured(json!({})))
+    }
+}
+
+async fn connect() -> KodegenConnection {
+    let (server_io, client_io) = tokio::io::duplex(4096);
+    tokio::spawn(async move {
+        let server = NotifyingServer.serve(server_io).await?;
+        server.waiting().await?;
+        anyhow::Ok(())
+    });
+
+    let service = KodegenClientHandler::new("test-client")
+        .serve(client_io)
+        .await
+        .expect("Failed to initialize client");
+    KodegenConnection::from_service(service)
+}
+
+fn expected_notifications() -> Vec<ServerNotification> {
+    vec![
+        ServerNotification::ToolListChanged,
+        ServerNotification::LoggingMessage {
+            level: LoggingLevel::Warning,
+            logger: Some("indexer".to_string()),
+            data: json!("disk almost full"),
+        },
+        ServerNotification::ResourceUpdated {
+            uri: "file:///schema.sql".to_string(),
+        },
+        ServerNotification::Progress {
+            token: ProgressToken(NumberOrString::String(Arc::from("job-1"))),
+            progress: 1.0,
+            total: Some(2.0),
+            message: Some("halfway".to_string()),
+        },
+        ServerNotification::PromptListChanged,
+    ]
+}
+
+/// Test that every subscriber, on cloned handles or the connection, sees every notification
+#[tokio::test]
+async fn test_notifications_fan_out_to_all_subscribers() {
+    let conn = connect().await;
+    let client = conn.client();
+    let clone = client.clone();
+
+    let streams = vec![client.subscribe(), clone.subscribe(), conn.notifications()];
+
+    client
+        .call_tool("notify", json!({}))
+        .await
+        .expect("Failed to call tool");
+
+    for stream in streams {
+        let received: Vec<_> = tokio::time::timeout(
+            Duration::from_secs(5),
+            stream.take(expected_notifications().len()).collect(),
+        )
+        .await
+        .expect("Timed out waiting for notifications");
+        assert_eq!(received, expected_notifications());
+    }
+}
+
+/// Test that a stream only sees notifications sent after it subscribed
+#[tokio::test]
+async fn test_late_subscriber_misses_earlier_notifications() {
+    let conn = connect().await;
+    let client = conn.client();
+
+    let mut early = client.subscribe();
+    client
+        .call_tool("notify", json!({}))
+        .await
+        .expect("Failed to call tool");
+    let first = tokio::time::timeout(Duration::from_secs(5), early.next())
+        .await
+        .expect("Timed out waiting for notification");
+    assert_eq!(first, Some(ServerNotification::ToolListChanged));
+
+    // Give the remaining notifications from the first call time to arrive
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let mut late = client.subscribe();
+    client
+        .call_tool("notify", json!({}))
+        .await
+        .expect("Failed to call tool");
+    let first = tokio::time::timeout(Duration::from_secs(5), late.next())
+        .await
+        .expect("Timed out waiting for notification");
+    assert_eq!(first, Some(ServerNotification::ToolListChanged));
+}
+
+/// Test that progress updates for the call are forwarded and keep it alive past the timeout
+#[tokio::test]
+async fn test_call_tool_with_progress_forwards_updates() {
+    let conn = connect().await;
+    // Shorter than the whole call (~200ms) but longer than the gap between updates
+    let client = conn.client().with_timeout(Duration::from_millis(150));
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+    let result = client
+        .call_tool_with_progress("steps", json!({}), tx)
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(result.structured_content, Some(json!({"steps": 4})));
+
+    let mut updates = Vec::new();
+    while let Ok(update) = rx.try_recv() {
+        updates.push(update);
+    }
+    let expected: Vec<_> = (1..=4)
+        .map(|step| ToolProgress {
+            progress: f64::from(step),
+            total: Some(4.0),
+            message: Some(format!("step {step}")),
+        })
+        .collect();
+    assert_eq!(updates, expected);
+}
+
+/// Test that a call without progress still times out
+#[tokio::test]
+async fn test_call_tool_with_progress_idle_timeout() {
+    let conn = connect().await;
+    let client = conn.client().with_timeout(Duration::from_millis(100));
+    let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+
+    match client.call_tool_with_progress("stall", json!({}), tx).await {
+        Err(ClientError::Timeout { operation, .. }) => assert!(operation.contains("stall")),
+        other => panic!("Expected Timeout error, got: {:?}", other),
+    }
+}
@@ -0,0 +1,141 @@
+// Integration tests for the native MCP prompt APIs on KodegenClient
+use kodegen_mcp_client::{ClientError, KodegenClientHandler, KodegenConnection};
+use rmcp::{
+    ErrorData, RoleServer, ServerHandler, ServiceExt,
+    model::{
+        GetPromptRequestParam, GetPromptResult, ListPromptsResult, PaginatedRequestParam, Prompt,
+        PromptMessage, PromptMessageContent, PromptMessageRole, ServerCapabilities, ServerInfo,
+    },
+    service::RequestContext,
+};
+use serde::Serialize;
+use serde_json::json;
+
+/// In-process server exposing two pages of prompts that echo their arguments
+struct PromptServer;
+
+impl ServerHandler for PromptServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder().enable_prompts().build(),
+            ..Default::default()
+        }
+    }
+
+    async fn list_prompts(
+        &self,
+        request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListPromptsResult, ErrorData> {
+        let (name, next_cursor) = match request.and_then(|r| r.cursor) {
+            None => ("code_review", Some("page-2".to_string())),
+            Some(_) => ("summarize", None),
+        };
+        Ok(ListPromptsResult {
+            prompts: vec![Prompt::new(name, None::<String>, None)],
+            next_cursor,
+            meta: None,
+        })
+    }
+
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<GetPromptResult, ErrorData> {
+        // Every argument must arrive as a string per the MCP spec
+        let arguments = request.arguments.unwrap_or_default();
+        if let Some((key, _)) = arguments.iter().find(|(_, v)| !v.is_string()) {
+            return Err(ErrorData::invalid_params(
+                format!("argument '{key}' is not a string"),
+                None,
+            ));
+        }
+        Ok(GetPromptResult {
+            description: Some(request.name),
+            messages: vec![PromptMessage::new_text(
+                PromptMessageRole::User,
+                serde_json::Value::Object(arguments).to_string(),
+            )],
+        })
+    }
+}
+
+async fn connect() -> KodegenConnection {
+    let (server_io, client_io) = tokio::io::duplex(4096);
+    tokio::spawn(async move {
+        let server = PromptServer.serve(server_io).await?;
+        server.waiting().await?;
+        anyhow::Ok(())
+    });
+
+    let service = KodegenClientHandler::new("test-client")
+        .serve(client_io)
+        .await
+        .expect("Failed to initialize client");
+    KodegenConnection::from_service(service)
+}
+
+#[derive(Serialize)]
+struct ReviewArgs<'a> {
+    language: &'a str,
+    max_findings: u32,
+    strict: bool,
+    focus: Option<&'a str>,
+}
+
+/// Test that list_prompts follows cursors across pages
+#[tokio::test]
+async fn test_list_prompts_paginates() {
+    let conn = connect().await;
+    let prompts = conn
+        .client()
+        .list_prompts()
+        .await
+        .expect("Failed to list prompts");
+
+    let names: Vec<_> = prompts.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["code_review", "summarize"]);
+}
+
+/// Test that typed arguments are rendered to strings
+#[tokio::test]
+async fn test_get_prompt_renders_typed_arguments() {
+    let conn = connect().await;
+    let args = ReviewArgs {
+        language: "rust",
+        max_findings: 10,
+        strict: true,
+        focus: None,
+    };
+
+    let result = conn
+        .client()
+        .get_prompt("code_review", args)
+        .await
+        .expect("Failed to get prompt");
+
+    assert_eq!(result.description.as_deref(), Some("code_review"));
+    let PromptMessageContent::Text { text } = &result.messages[0].content else {
+        panic!(
+            "Expected text message, got: {:?}",
+            result.messages[0].content
+        );
+    };
+    let echoed: serde_json::Value = serde_json::from_str(text).expect("Invalid echo");
+    assert_eq!(
+        echoed,
+        json!({"language": "rust", "max_findings": "10", "strict": "true"})
+    );
+}
+
+/// Test that non-object arguments are rejected before sending
+#[tokio::test]
+async fn test_get_prompt_rejects_non_object_arguments() {
+    let conn = connect().await;
+
+    match conn.client().get_prompt("code_review", vec!["rust"]).await {
+        Err(ClientError::Protocol(msg)) => assert!(msg.contains("got array")),
+        other => panic!("Expected Protocol error, got: {:?}", other),
+    }
+}
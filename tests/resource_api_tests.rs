@@ -0,0 +1,179 @@
+// Integration tests for the resource APIs on KodegenClient
+use kodegen_mcp_client::{ClientError, KodegenClientHandler, KodegenConnection};
+use rmcp::{
+    ErrorData, RoleServer, ServerHandler, ServiceExt,
+    model::{
+        AnnotateAble, ListResourceTemplatesResult, ListResourcesResult, PaginatedRequestParam,
+        RawResource, RawResourceTemplate, ReadResourceRequestParam, ReadResourceResult,
+        ResourceContents, ServerCapabilities, ServerInfo, SubscribeRequestParam,
+        UnsubscribeRequestParam,
+    },
+    service::RequestContext,
+};
+use std::time::Duration;
+
+/// In-process server exposing two pages of resources
+struct ResourceServer;
+
+impl ServerHandler for ResourceServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder()
+                .enable_resources()
+                .enable_resources_subscribe()
+                .build(),
+            ..Default::default()
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, ErrorData> {
+        let cursor = request.and_then(|r| r.cursor);
+        let (uri, next_cursor) = match cursor.as_deref() {
+            None => ("file:///schema.sql", Some("page-2".to_string())),
+            Some("page-2") => ("file:///README.md", None),
+            Some(other) => {
+                return Err(ErrorData::invalid_params(
+                    format!("bad cursor {other}"),
+                    None,
+                ));
+            }
+        };
+        Ok(ListResourcesResult {
+            resources: vec![RawResource::new(uri, uri).no_annotation()],
+            next_cursor,
+            meta: None,
+        })
+    }
+
+    async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, ErrorData> {
+        Ok(ListResourceTemplatesResult {
+            resource_templates: vec![
+                RawResourceTemplate {
+                    uri_template: "file:///{path}".to_string(),
+                    name: "file".to_string(),
+                    title: None,
+                    description: None,
+                    mime_type: None,
+                }
+                .no_annotation(),
+            ],
+            next_cursor: None,
+            meta: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, ErrorData> {
+        if request.uri == "file:///slow" {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        }
+        Ok(ReadResourceResult {
+            contents: vec![ResourceContents::text("CREATE TABLE users;", request.uri)],
+        })
+    }
+
+    async fn subscribe(
+        &self,
+        _request: SubscribeRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), ErrorData> {
+        Ok(())
+    }
+
+    async fn unsubscribe(
+        &self,
+        _request: UnsubscribeRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), ErrorData> {
+        Ok(())
+    }
+}
+
+async fn connect() -> KodegenConnection {
+    let (server_io, client_io) = tokio::io::duplex(4096);
+    tokio::spawn(async move {
+        let server = ResourceServer.serve(server_io).await?;
+        server.waiting().await?;
+        anyhow::Ok(())
+    });
+
+    let service = KodegenClientHandler::new("test-client")
+        .serve(client_io)
+        .await
+        .expect("Failed to initialize client");
+    KodegenConnection::from_service(service)
+}
+
+/// Test that list_resources follows cursors across pages
+#[tokio::test]
+async fn test_list_resources_paginates() {
+    let conn = connect().await;
+    let resources = conn
+        .client()
+        .list_resources()
+        .await
+        .expect("Failed to list resources");
+
+    let uris: Vec<_> = resources.iter().map(|r| r.uri.as_str()).collect();
+    assert_eq!(uris, ["file:///schema.sql", "file:///README.md"]);
+}
+
+/// Test listing templates, reading and (un)subscribing
+#[tokio::test]
+async fn test_resource_round_trip() {
+    let conn = connect().await;
+    let client = conn.client();
+
+    let templates = client
+        .list_resource_templates()
+        .await
+        .expect("Failed to list resource templates");
+    assert_eq!(templates.len(), 1);
+    assert_eq!(templates[0].uri_template, "file:///{path}");
+
+    let result = client
+        .read_resource("file:///schema.sql")
+        .await
+        .expect("Failed to read resource");
+    match &result.contents[0] {
+        ResourceContents::TextResourceContents { text, uri, .. } => {
+            assert_eq!(text, "CREATE TABLE users;");
+            assert_eq!(uri, "file:///schema.sql");
+        }
+        other => panic!("Expected text contents, got: {:?}", other),
+    }
+
+    client
+        .subscribe_resource("file:///schema.sql")
+        .await
+        .expect("Failed to subscribe");
+    client
+        .unsubscribe_resource("file:///schema.sql")
+        .await
+        .expect("Failed to unsubscribe");
+}
+
+/// Test that read_resource honors the handle timeout
+#[tokio::test]
+async fn test_read_resource_timeout() {
+    let conn = connect().await;
+    let client = conn.client().with_timeout(Duration::from_millis(100));
+
+    match client.read_resource("file:///slow").await {
+        Err(ClientError::Timeout { operation, .. }) => {
+            assert_eq!(operation, "read_resource 'file:///slow'");
+        }
+        other => panic!("Expected Timeout error, got: {:?}", other),
+    }
+}
@@ -0,0 +1,213 @@
+// Integration tests for paging through fs_search with SearchSession
+use kodegen_mcp_client::{
+    KodegenClientHandler, KodegenConnection, SearchSession,
+    requests::{SearchType, StartSearchArgs},
+    tools,
+};
+use rmcp::{
+    ErrorData, RoleServer, ServerHandler, ServiceExt,
+    model::{CallToolRequestParam, CallToolResult, ServerCapabilities, ServerInfo},
+    service::RequestContext,
+};
+use serde_json::json;
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tokio_stream::StreamExt;
+
+/// Files found by each results poll; the search completes after the last one
+const SCRIPT: &[&[&str]] = &[&["a.rs", "b.rs"], &[], &[], &["c.rs"]];
+
+#[derive(Default)]
+struct Search {
+    found: Vec<&'static str>,
+    polls: Vec<(u64, Instant)>,
+    stopped: bool,
+}
+
+/// In-process server running one scripted `fs_search` session
+#[derive(Clone, Default)]
+struct SearchServer {
+    search: Arc<Mutex<Search>>,
+    /// Most results the server returns, however many it finds
+    cap: Option<usize>,
+}
+
+impl ServerHandler for SearchServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            ..Default::default()
+        }
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        assert_eq!(request.name, tools::filesystem::SEARCH);
+        let args = json!(request.arguments);
+        let mut search = self.search.lock().expect("Lock poisoned");
+        let output = match args["action"].as_str() {
+            None => json!({"sessionId": "search-1"}),
+            Some("results") => {
+                assert_eq!(args["sessionId"], "search-1");
+                let offset = args["offset"].as_u64().expect("offset");
+                let step = search.polls.len();
+                search.polls.push((offset, Instant::now()));
+                if let Some(files) = SCRIPT.get(step) {
+                    search.found.extend_from_slice(files);
+                }
+                let end = self.cap.unwrap_or(usize::MAX).min(search.found.len());
+                let results: Vec<_> = search.found[offset as usize..end]
+                    .iter()
+                    .map(|file| json!({"file": file}))
+                    .collect();
+                json!({
+                    "sessionId": "search-1",
+                    "results": results,
+                    "total_results": search.found.len(),
+                    "is_complete": step >= SCRIPT.len(),
+                })
+            }
+            Some("stop") => {
+                let stopped = !search.stopped;
+                search.stopped = true;
+                json!({"session_id": args["sessionId"], "stopped": stopped})
+            }
+            other => panic!("Unexpected action {:?}", other),
+        };
+        Ok(CallToolResult::structured(output))
+    }
+}
+
+async fn start(server: SearchServer) -> (KodegenConnection, SearchSession) {
+    let (server_io, client_io) = tokio::io::duplex(4096);
+    tokio::spawn(async move {
+        let server = server.serve(server_io).await?;
+        server.waiting().await?;
+        anyhow::Ok(())
+    });
+    let service = KodegenClientHandler::new("test-client")
+        .serve(client_io)
+        .await
+        .expect("Failed to initialize client");
+    let conn = KodegenConnection::from_service(service);
+
+    let session = conn
+        .client()
+        .start_search(StartSearchArgs::new("/repo", "*.rs", SearchType::Files))
+        .await
+        .expect("Failed to start search")
+        .backoff(Duration::from_millis(20), Duration::from_millis(30));
+    (conn, session)
+}
+
+/// Test that results stream once each and polls back off while nothing is new
+#[tokio::test]
+async fn test_results_stream_with_backoff() {
+    let server = SearchServer::default();
+    let (_conn, mut session) = start(server.clone()).await;
+    assert_eq!(session.session_id(), "search-1");
+
+    let files: Vec<String> = session
+        .results()
+        .map(|found| found.map(|found| found.file))
+        .collect::<Result<_, _>>()
+        .await
+        .expect("Failed to stream results");
+    assert_eq!(files, ["a.rs", "b.rs", "c.rs"]);
+    assert!(session.is_complete());
+    assert_eq!(session.total_results(), 3);
+    assert!(session.next_page().await.expect("Failed to poll").is_none());
+
+    let search = server.search.lock().expect("Lock poisoned");
+    let offsets: Vec<_> = search.polls.iter().map(|(offset, _)| *offset).collect();
+    assert_eq!(offsets, [0, 2, 2, 2, 3]);
+    let gaps: Vec<_> = search
+        .polls
+        .windows(2)
+        .map(|pair| pair[1].1 - pair[0].1)
+        .collect();
+    assert!(gaps[1] >= Duration::from_millis(20), "{:?}", gaps);
+    assert!(gaps[2] >= Duration::from_millis(30), "{:?}", gaps);
+    // Completed searches are not stopped
+    assert!(!search.stopped);
+}
+
+/// Test single polls and pages
+#[tokio::test]
+async fn test_poll_and_next_page() {
+    let (_conn, mut session) = start(SearchServer::default()).await;
+
+    let first = session.poll().await.expect("Failed to poll");
+    assert_eq!(first.results.len(), 2);
+    assert!(!first.is_complete);
+    assert_eq!(session.offset(), 2);
+
+    let page = session
+        .next_page()
+        .await
+        .expect("Failed to poll")
+        .expect("Search ended early");
+    assert_eq!(page[0].file, "c.rs");
+    assert!(!session.is_complete());
+    assert!(session.next_page().await.expect("Failed to poll").is_none());
+    assert!(session.is_complete());
+}
+
+/// Test that stopped searches return no more pages
+#[tokio::test]
+async fn test_stop() {
+    let server = SearchServer::default();
+    let (_conn, mut session) = start(server.clone()).await;
+    session.poll().await.expect("Failed to poll");
+
+    let stopped = session.stop().await.expect("Failed to stop");
+    assert!(stopped.stopped);
+    assert!(session.next_page().await.expect("Failed to poll").is_none());
+    assert_eq!(server.search.lock().expect("Lock poisoned").polls.len(), 1);
+}
+
+/// Test that dropping an unfinished session stops the search on the server
+#[tokio::test]
+async fn test_drop_stops_search() {
+    let server = SearchServer::default();
+    let (_conn, mut session) = start(server.clone()).await;
+    session.poll().await.expect("Failed to poll");
+    drop(session);
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !server.search.lock().expect("Lock poisoned").stopped {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("Search was not stopped");
+}
+
+/// Test that searches returning fewer results than they found still complete
+#[tokio::test]
+async fn test_capped_results_complete() {
+    let server = SearchServer {
+        cap: Some(2),
+        ..SearchServer::default()
+    };
+    let (_conn, mut session) = start(server.clone()).await;
+
+    let files: Vec<String> = session
+        .results()
+        .map(|found| found.map(|found| found.file))
+        .collect::<Result<_, _>>()
+        .await
+        .expect("Failed to stream results");
+    assert_eq!(files, ["a.rs", "b.rs"]);
+    assert!(session.is_complete());
+    assert_eq!(session.total_results(), 3);
+
+    let search = server.search.lock().expect("Lock poisoned");
+    assert_eq!(search.polls.len(), SCRIPT.len() + 1);
+    assert!(!search.stopped);
+}
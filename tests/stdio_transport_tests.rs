@@ -10,9 +10,8 @@ async fn test_invalid_command_error() {
     assert!(result.is_err());
     if let Err(error) = result {
         match error {
-            ClientError::Connection(msg) => {
-                assert!(msg.contains("Failed to spawn process"));
-                assert!(msg.contains("nonexistent_command_12345"));
+            ClientError::Connection { message, .. } => {
+                assert!(message.contains("nonexistent_command_12345"));
             }
             ClientError::Io(_) => {
                 // Also acceptable - spawn failure as IO error
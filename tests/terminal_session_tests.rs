@@ -0,0 +1,204 @@
+// Integration tests for driving terminal commands through TerminalSession
+use kodegen_mcp_client::{
+    KodegenClientHandler, KodegenConnection, TerminalSession, requests::StartTerminalCommandArgs,
+    tools,
+};
+use rmcp::{
+    ErrorData, RoleServer, ServerHandler, ServiceExt,
+    model::{CallToolRequestParam, CallToolResult, ServerCapabilities, ServerInfo},
+    service::RequestContext,
+};
+use serde_json::json;
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio_stream::StreamExt;
+
+/// Bytes returned per read, so output arrives in several chunks
+const CHUNK: usize = 8;
+
+#[derive(Default)]
+struct Process {
+    /// Output the command produces, one entry per read; empty entries are quiet reads
+    script: VecDeque<&'static str>,
+    output: String,
+    exited: bool,
+    exit_code: Option<i32>,
+    actions: Vec<serde_json::Value>,
+}
+
+/// In-process server simulating one command run through `start_terminal`
+#[derive(Clone, Default)]
+struct TerminalServer {
+    process: Arc<Mutex<Process>>,
+}
+
+impl ServerHandler for TerminalServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            ..Default::default()
+        }
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let args = json!(request.arguments);
+        let mut process = self.process.lock().expect("Lock poisoned");
+        if request.name == tools::terminal::START_TERMINAL {
+            process.script = ["Compiling\n", "", "", "Finished\n"].into();
+            return Ok(CallToolResult::structured(json!({"pid": 42})));
+        }
+
+        assert_eq!(request.name, tools::terminal::TERMINAL);
+        assert_eq!(args["pid"], 42);
+        process.actions.push(args.clone());
+        let output = match args["action"].as_str() {
+            Some("read") => {
+                match process.script.pop_front() {
+                    Some(line) => process.output.push_str(line),
+                    None if !process.exited => {
+                        process.exited = true;
+                        process.exit_code = Some(0);
+                    }
+                    None => {}
+                }
+                let offset = args["offset"].as_u64().expect("offset") as usize;
+                let end = (offset + CHUNK).min(process.output.len());
+                json!({
+                    "pid": 42,
+                    "output": &process.output[offset..end],
+                    "offset": offset,
+                    "next_offset": end,
+                    "running": !process.exited,
+                    "exit_code": process.exit_code,
+                })
+            }
+            Some("input") => {
+                let input = args["input"].as_str().expect("input");
+                let echoed = format!("> {}", input);
+                process.output.push_str(&echoed);
+                json!({"pid": 42, "bytes_written": input.len()})
+            }
+            Some("kill") => {
+                let killed = !process.exited;
+                process.script.clear();
+                process.exited = true;
+                json!({"pid": 42, "killed": killed, "exit_code": process.exit_code})
+            }
+            other => panic!("Unexpected action {:?}", other),
+        };
+        Ok(CallToolResult::structured(output))
+    }
+}
+
+async fn start(server: TerminalServer) -> (KodegenConnection, TerminalSession) {
+    let (server_io, client_io) = tokio::io::duplex(4096);
+    tokio::spawn(async move {
+        let server = server.serve(server_io).await?;
+        server.waiting().await?;
+        anyhow::Ok(())
+    });
+    let service = KodegenClientHandler::new("test-client")
+        .serve(client_io)
+        .await
+        .expect("Failed to initialize client");
+    let conn = KodegenConnection::from_service(service);
+
+    let session = conn
+        .client()
+        .start_terminal_command(StartTerminalCommandArgs::new("cargo build"))
+        .await
+        .expect("Failed to start command")
+        .poll_interval(Duration::from_millis(1));
+    (conn, session)
+}
+
+/// Test that reads continue from the previous read's offset
+#[tokio::test]
+async fn test_read_output_offsets() {
+    let (_conn, mut session) = start(TerminalServer::default()).await;
+    assert_eq!(session.pid(), 42);
+
+    let first = session.read_output().await.expect("Failed to read");
+    assert_eq!((first.output.as_str(), first.offset), ("Compilin", 0));
+    assert!(first.running);
+    let second = session.read_output().await.expect("Failed to read");
+    assert_eq!((second.output.as_str(), second.offset), ("g\n", 8));
+    assert_eq!(session.offset(), 10);
+
+    let quiet = session.read_output().await.expect("Failed to read");
+    assert_eq!(quiet.output, "");
+    assert_eq!(session.offset(), 10);
+
+    let again = session.read_output_at(0).await.expect("Failed to read");
+    assert_eq!(again.output, "Compilin");
+    assert_eq!(session.offset(), 10);
+    assert!(!session.is_finished());
+}
+
+/// Test that the output stream yields every chunk once and ends at exit
+#[tokio::test]
+async fn test_output_stream() {
+    let (_conn, mut session) = start(TerminalServer::default()).await;
+
+    let chunks: Vec<String> = session
+        .output_stream()
+        .collect::<Result<_, _>>()
+        .await
+        .expect("Failed to stream output");
+    assert_eq!(chunks, ["Compilin", "g\n", "Finished", "\n"]);
+    assert!(session.is_finished());
+    assert_eq!(session.exit_code(), Some(0));
+
+    let mut drained = std::pin::pin!(session.output_stream());
+    assert!(drained.next().await.is_none());
+}
+
+/// Test that input is sent to the command and wait returns the exit code
+#[tokio::test]
+async fn test_send_input_and_wait() {
+    let server = TerminalServer::default();
+    let (_conn, mut session) = start(server.clone()).await;
+
+    let sent = session.send_input("y\n").await.expect("Failed to send");
+    assert_eq!(sent.bytes_written, 2);
+    assert_eq!(session.wait().await.expect("Failed to wait"), Some(0));
+    assert!(session.is_finished());
+
+    let process = server.process.lock().expect("Lock poisoned");
+    assert_eq!(process.output, "> y\nCompiling\nFinished\n");
+    assert_eq!(
+        process.actions[0],
+        json!({"action": "input", "pid": 42, "input": "y\n"})
+    );
+    assert_eq!(
+        process.actions[1],
+        json!({"action": "read", "pid": 42, "offset": 0})
+    );
+}
+
+/// Test that killing a command ends it without an exit code
+#[tokio::test]
+async fn test_kill() {
+    let (_conn, mut session) = start(TerminalServer::default()).await;
+    session.read_output().await.expect("Failed to read");
+
+    let killed = session.kill().await.expect("Failed to kill");
+    assert!(killed.killed);
+    assert_eq!(
+        tokio::time::timeout(Duration::from_secs(5), session.wait())
+            .await
+            .expect("Wait timed out")
+            .expect("Failed to wait"),
+        None
+    );
+
+    let again = session.kill().await.expect("Failed to kill");
+    assert!(!again.killed);
+}
@@ -0,0 +1,158 @@
+// Integration tests for the per-connection tool catalog
+use kodegen_mcp_client::{KodegenClientHandler, KodegenConnection, ServerNotification};
+use rmcp::{
+    ErrorData, RoleServer, ServerHandler, ServiceExt,
+    model::{
+        CallToolRequestParam, CallToolResult, ListToolsResult, PaginatedRequestParam,
+        ServerCapabilities, ServerInfo, Tool,
+    },
+    service::RequestContext,
+};
+use serde_json::json;
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+use tokio_stream::StreamExt;
+
+/// In-process server listing "alpha" and "beta" across two pages
+///
+/// "install" adds "gamma" and announces `tools/list_changed`. Every page
+/// request is counted.
+#[derive(Clone, Default)]
+struct CatalogServer {
+    installed: Arc<AtomicBool>,
+    pages: Arc<AtomicUsize>,
+}
+
+fn tool(name: &'static str) -> Tool {
+    Tool::new(name, "test tool", Arc::new(Default::default()))
+}
+
+impl ServerHandler for CatalogServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_tool_list_changed()
+                .build(),
+            ..Default::default()
+        }
+    }
+
+    async fn list_tools(
+        &self,
+        request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, ErrorData> {
+        self.pages.fetch_add(1, Ordering::SeqCst);
+        let (tools, next_cursor) = match request.and_then(|r| r.cursor) {
+            None => (
+                vec![tool("alpha"), tool("install")],
+                Some("page-2".to_string()),
+            ),
+            Some(_) if self.installed.load(Ordering::SeqCst) => {
+                (vec![tool("beta"), tool("gamma")], None)
+            }
+            Some(_) => (vec![tool("beta")], None),
+        };
+        Ok(ListToolsResult {
+            tools,
+            next_cursor,
+            meta: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        _request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        self.installed.store(true, Ordering::SeqCst);
+        context
+            .peer
+            .notify_tool_list_changed()
+            .await
+            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+        Ok(CallToolResult::structured(json!({})))
+    }
+}
+
+async fn connect(server: CatalogServer) -> KodegenConnection {
+    let (server_io, client_io) = tokio::io::duplex(4096);
+    tokio::spawn(async move {
+        let server = server.serve(server_io).await?;
+        server.waiting().await?;
+        anyhow::Ok(())
+    });
+
+    let service = KodegenClientHandler::new("test-client")
+        .serve(client_io)
+        .await
+        .expect("Failed to initialize client");
+    KodegenConnection::from_service(service)
+}
+
+/// Test that lookups on any handle share one listing
+#[tokio::test]
+async fn test_lookups_use_cached_catalog() {
+    let server = CatalogServer::default();
+    let conn = connect(server.clone()).await;
+    let client = conn.client();
+
+    assert_eq!(
+        client.tool_names().await.expect("Failed to list names"),
+        ["alpha", "install", "beta"]
+    );
+    assert_eq!(server.pages.load(Ordering::SeqCst), 2);
+
+    let tool = client
+        .tool("beta")
+        .await
+        .expect("Failed to look up tool")
+        .expect("beta should exist");
+    assert_eq!(tool.name, "beta");
+    assert!(
+        !conn
+            .client()
+            .has_tool("gamma")
+            .await
+            .expect("Lookup failed")
+    );
+
+    // Warm cache: no further round trips, even from other handles
+    assert_eq!(server.pages.load(Ordering::SeqCst), 2);
+}
+
+/// Test that list_tools refreshes the catalog it fills
+#[tokio::test]
+async fn test_list_tools_fills_catalog() {
+    let server = CatalogServer::default();
+    let conn = connect(server.clone()).await;
+    let client = conn.client();
+
+    client.list_tools().await.expect("Failed to list tools");
+    let pages = server.pages.load(Ordering::SeqCst);
+    assert!(client.has_tool("alpha").await.expect("Lookup failed"));
+    assert_eq!(server.pages.load(Ordering::SeqCst), pages);
+}
+
+/// Test that tools/list_changed invalidates the catalog
+#[tokio::test]
+async fn test_list_changed_refreshes_catalog() {
+    let server = CatalogServer::default();
+    let conn = connect(server.clone()).await;
+    let client = conn.client().with_argument_validation(false);
+    let mut notifications = client.subscribe();
+
+    assert!(!client.has_tool("gamma").await.expect("Lookup failed"));
+
+    client
+        .call_tool("install", json!({}))
+        .await
+        .expect("Failed to call tool");
+    while notifications.next().await != Some(ServerNotification::ToolListChanged) {}
+
+    assert!(client.has_tool("gamma").await.expect("Lookup failed"));
+    assert_eq!(server.pages.load(Ordering::SeqCst), 4);
+}
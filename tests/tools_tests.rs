@@ -0,0 +1,54 @@
+// Integration tests for the tool name constants
+use kodegen_mcp_client::tools::{self, ToolCategory, category_of};
+use std::collections::HashSet;
+
+/// Test that constants carry the names the servers register
+#[test]
+fn test_tool_names() {
+    assert_eq!(tools::filesystem::READ_FILE, "fs_read_file");
+    assert_eq!(tools::database::EXECUTE_SQL, "db_execute_sql");
+    assert_eq!(tools::github::LIST_ISSUES, "github_list_issues");
+    assert_eq!(tools::citescrape::SCRAPE_URL, "scrape_url");
+    assert_eq!(tools::terminal::TERMINAL, "terminal");
+}
+
+/// Test that names map back to their category
+#[test]
+fn test_category_of() {
+    assert_eq!(
+        category_of(tools::filesystem::SEARCH),
+        Some(ToolCategory::Filesystem)
+    );
+    assert_eq!(category_of("git_commit"), Some(ToolCategory::Git));
+    assert_eq!(
+        category_of(tools::introspection::INSPECT_USAGE_STATS),
+        Some(ToolCategory::Introspection)
+    );
+    assert_eq!(category_of("no_such_tool"), None);
+    assert_eq!(category_of(""), None);
+}
+
+/// Test that every tool belongs to exactly one category
+#[test]
+fn test_categories_are_disjoint() {
+    let mut seen = HashSet::new();
+    for &category in ToolCategory::ALL {
+        assert!(!category.tools().is_empty(), "{category} has no tools");
+        for &name in category.tools() {
+            assert!(seen.insert(name), "{name} is listed twice");
+            assert_eq!(category_of(name), Some(category));
+        }
+    }
+    assert!(seen.len() > 100);
+}
+
+/// Test that categories display their metadata names
+#[test]
+fn test_category_names() {
+    assert_eq!(ToolCategory::GitHub.as_str(), "github");
+    assert_eq!(ToolCategory::ClaudeAgent.to_string(), "claude_agent");
+    assert_eq!(
+        ToolCategory::SequentialThinking.to_string(),
+        "sequential_thinking"
+    );
+}
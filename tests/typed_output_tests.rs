@@ -0,0 +1,277 @@
+// Integration tests for structured_content and outputSchema handling in typed calls
+use kodegen_mcp_client::{
+    CallOptions, ClientError, KodegenClientHandler, KodegenConnection,
+    responses::SpawnClaudeAgentResponse,
+};
+use rmcp::{
+    ErrorData, RoleServer, ServerHandler, ServiceExt,
+    model::{
+        CallToolRequestParam, CallToolResult, Content, ListToolsResult, PaginatedRequestParam,
+        ServerCapabilities, ServerInfo, Tool,
+    },
+    service::RequestContext,
+};
+use serde::Deserialize;
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct FileInfo {
+    path: String,
+    size: u64,
+}
+
+/// In-process server with a "stat" tool that declares an outputSchema
+///
+/// The returned structured content depends on the `variant` argument; the
+/// "agents" tool behaves the same but declares no outputSchema.
+struct StatServer;
+
+fn stat_tool() -> Tool {
+    let mut tool = Tool::new("stat", "Describe a file", Arc::new(Default::default()));
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "path": {"type": "string"},
+            "size": {"type": "integer", "minimum": 0}
+        },
+        "required": ["path", "size"]
+    });
+    tool.output_schema = Some(Arc::new(
+        schema.as_object().expect("Schema is an object").clone(),
+    ));
+    tool
+}
+
+impl ServerHandler for StatServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            ..Default::default()
+        }
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, ErrorData> {
+        let agents = Tool::new("agents", "Spawn agents", Arc::new(Default::default()));
+        Ok(ListToolsResult {
+            tools: vec![stat_tool(), agents],
+            next_cursor: None,
+            meta: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let variant = request
+            .arguments
+            .as_ref()
+            .and_then(|args| args.get("variant"))
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+
+        let result = match variant.as_str() {
+            // Human-readable text alongside machine-readable structured content
+            "both" => CallToolResult {
+                content: vec![Content::text("README.md is 42 bytes")],
+                structured_content: Some(json!({"path": "README.md", "size": 42})),
+                is_error: Some(false),
+                meta: None,
+            },
+            "text" => {
+                CallToolResult::success(vec![Content::text(r#"{"path": "Cargo.toml", "size": 7}"#)])
+            }
+            "invalid" => CallToolResult::structured(json!({"path": 5, "size": -1})),
+            "agents" => CallToolResult::structured(json!({
+                "session_ids": ["agent-1", "agent-2"],
+                "worker_count": request.arguments.as_ref().and_then(|args| args.get("workers")),
+            })),
+            "missing" => CallToolResult::error(vec![
+                Content::text("No such file"),
+                Content::text("missing.txt"),
+            ]),
+            other => {
+                return Err(ErrorData::invalid_params(
+                    format!("unknown variant {other}"),
+                    None,
+                ));
+            }
+        };
+        Ok(result)
+    }
+}
+
+async fn connect() -> KodegenConnection {
+    let (server_io, client_io) = tokio::io::duplex(4096);
+    tokio::spawn(async move {
+        let server = StatServer.serve(server_io).await?;
+        server.waiting().await?;
+        anyhow::Ok(())
+    });
+
+    let service = KodegenClientHandler::new("test-client")
+        .serve(client_io)
+        .await
+        .expect("Failed to initialize client");
+    KodegenConnection::from_service(service)
+}
+
+/// Test that structured_content wins over (non-JSON) text content
+#[tokio::test]
+async fn test_typed_prefers_structured_content() {
+    let conn = connect().await;
+
+    let info: FileInfo = conn
+        .client()
+        .call_tool_typed("stat", json!({"variant": "both"}))
+        .await
+        .expect("Failed to parse structured content");
+    assert_eq!(
+        info,
+        FileInfo {
+            path: "README.md".to_string(),
+            size: 42
+        }
+    );
+}
+
+/// Test that text content is still parsed when no structured_content is sent
+#[tokio::test]
+async fn test_typed_falls_back_to_text() {
+    let conn = connect().await;
+    let client = conn.client();
+    let tools = client.list_tools().await.expect("Failed to list tools");
+    let options = CallOptions::new().output_schema_from(&tools[0]);
+
+    let info: FileInfo = client
+        .call_tool_typed_with("stat", json!({"variant": "text"}), &options)
+        .await
+        .expect("Failed to parse text content");
+    assert_eq!(info.path, "Cargo.toml");
+}
+
+/// Test that outputSchema violations are reported with JSON pointers
+#[tokio::test]
+async fn test_output_schema_mismatch() {
+    let conn = connect().await;
+    let client = conn.client();
+    let tools = client.list_tools().await.expect("Failed to list tools");
+    let options = CallOptions::new().output_schema_from(&tools[0]);
+
+    match client
+        .call_tool_typed_with::<FileInfo>("stat", json!({"variant": "invalid"}), &options)
+        .await
+    {
+        Err(ClientError::OutputSchemaMismatch {
+            tool_name,
+            violations,
+        }) => {
+            assert_eq!(tool_name, "stat");
+            let mut paths: Vec<_> = violations
+                .iter()
+                .map(|v| v.instance_path.as_str())
+                .collect();
+            paths.sort_unstable();
+            assert_eq!(paths, ["/path", "/size"]);
+        }
+        other => panic!("Expected OutputSchemaMismatch error, got: {:?}", other),
+    }
+
+    // Plain typed calls use the schema from the cached tool catalog
+    let result = client
+        .call_tool_typed::<FileInfo>("stat", json!({"variant": "invalid"}))
+        .await;
+    assert!(matches!(
+        result,
+        Err(ClientError::OutputSchemaMismatch { .. })
+    ));
+
+    // Without a schema the same payload fails only at deserialization
+    let result = client
+        .call_tool_typed::<FileInfo>("agents", json!({"variant": "invalid"}))
+        .await;
+    assert!(matches!(result, Err(ClientError::ParseError { .. })));
+}
+
+/// Test that results flagged with isError become ToolError instead of ParseError
+#[tokio::test]
+async fn test_tool_error_result() {
+    let conn = connect().await;
+    let client = conn.client();
+    let args = json!({"variant": "missing"});
+
+    // Plain call_tool still hands the raw result back
+    let result = client
+        .call_tool("stat", args.clone())
+        .await
+        .expect("Failed to call tool");
+    assert_eq!(result.is_error, Some(true));
+
+    let assert_tool_error = |result: Result<_, ClientError>| match result {
+        Err(ClientError::ToolError {
+            tool_name,
+            message,
+            content,
+        }) => {
+            assert_eq!(tool_name, "stat");
+            assert_eq!(message, "No such file\nmissing.txt");
+            assert_eq!(content.len(), 2);
+        }
+        other => panic!("Expected ToolError, got: {:?}", other.map(|_| ())),
+    };
+
+    assert_tool_error(
+        client
+            .call_tool_checked("stat", args.clone())
+            .await
+            .map(|_| ()),
+    );
+    assert_tool_error(
+        client
+            .call_tool_typed::<FileInfo>("stat", args)
+            .await
+            .map(|_| ()),
+    );
+}
+
+/// Test that call_tool_validated enforces Validate invariants
+#[tokio::test]
+async fn test_call_tool_validated() {
+    let conn = connect().await;
+    let client = conn.client();
+
+    let response: SpawnClaudeAgentResponse = client
+        .call_tool_validated("agents", json!({"variant": "agents", "workers": 2}))
+        .await
+        .expect("Consistent response should validate");
+    assert_eq!(response.session_ids.len(), 2);
+
+    match client
+        .call_tool_validated::<SpawnClaudeAgentResponse>(
+            "agents",
+            json!({"variant": "agents", "workers": 3}),
+        )
+        .await
+    {
+        Err(ClientError::ValidationError { tool_name, errors }) => {
+            assert_eq!(tool_name, "agents");
+            let paths: Vec<_> = errors.iter().map(|e| e.path.as_str()).collect();
+            assert_eq!(paths, ["/session_ids"]);
+        }
+        other => panic!("Expected ValidationError, got: {:?}", other),
+    }
+
+    // Types without invariants go through the default no-op validation
+    let value: serde_json::Value = client
+        .call_tool_validated("agents", json!({"variant": "agents", "workers": 3}))
+        .await
+        .expect("Untyped JSON has nothing to validate");
+    assert_eq!(value["worker_count"], 3);
+}
@@ -0,0 +1,225 @@
+// Integration tests for #[derive(Validate)]
+use kodegen_mcp_client::{
+    responses::{GitHubIssuesResponse, SpawnClaudeAgentResponse},
+    validation::{
+        Validate, above_maximum_error, below_minimum_error, count_mismatch_error, empty_field_error,
+    },
+};
+use serde_json::json;
+
+fn no_spaces(value: &String) -> Result<(), String> {
+    if value.contains(' ') {
+        return Err(format!("'{}' contains spaces", value));
+    }
+    Ok(())
+}
+
+#[derive(serde::Deserialize, Validate)]
+struct Listing {
+    total: u64,
+    #[validate(len_eq = "total", non_empty)]
+    items: Vec<String>,
+    #[validate(range(min = 1, max = 100))]
+    page_size: u32,
+    #[serde(rename = "nextCursor")]
+    #[validate(non_empty, custom = "no_spaces")]
+    cursor: String,
+}
+
+fn listing() -> Listing {
+    Listing {
+        total: 2,
+        items: vec!["a".to_string(), "b".to_string()],
+        page_size: 50,
+        cursor: "page-2".to_string(),
+    }
+}
+
+#[derive(serde::Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+struct SearchPage {
+    #[validate(non_empty)]
+    session_id: String,
+    #[validate(range(max = 1000))]
+    max_results: u32,
+    #[serde(rename(serialize = "out", deserialize = "matches"))]
+    #[validate(non_empty)]
+    found_files: Vec<String>,
+}
+
+#[derive(serde::Deserialize, Validate)]
+#[serde(rename_all = "SCREAMING-KEBAB-CASE")]
+struct Headers {
+    #[validate(non_empty)]
+    content_type: String,
+}
+
+#[derive(Validate)]
+struct NoRules {
+    _name: String,
+}
+
+/// Every (path, message) pair reported for `value`
+fn failures(value: &impl Validate) -> Vec<(String, String)> {
+    match value.validate() {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors
+            .into_iter()
+            .map(|error| (error.path, error.message))
+            .collect(),
+    }
+}
+
+fn failure(path: &str, message: String) -> (String, String) {
+    (path.to_string(), message)
+}
+
+/// Test that a valid struct passes every rule
+#[test]
+fn test_valid_struct() {
+    assert_eq!(listing().validate(), Ok(()));
+    assert_eq!(
+        NoRules {
+            _name: String::new()
+        }
+        .validate(),
+        Ok(())
+    );
+}
+
+/// Test that each rule reports the shared helper's message at the field's pointer
+#[test]
+fn test_rule_messages() {
+    let mut value = listing();
+    value.total = 3;
+    assert_eq!(
+        failures(&value),
+        [failure("/items", count_mismatch_error("total", 3, 2))]
+    );
+
+    let mut value = listing();
+    value.page_size = 0;
+    assert_eq!(
+        failures(&value),
+        [failure(
+            "/page_size",
+            below_minimum_error("page_size", 0, 1)
+        )]
+    );
+    value.page_size = 101;
+    assert_eq!(
+        failures(&value),
+        [failure(
+            "/page_size",
+            above_maximum_error("page_size", 101, 100)
+        )]
+    );
+
+    let mut value = listing();
+    value.cursor = "page 2".to_string();
+    assert_eq!(
+        failures(&value),
+        [failure(
+            "/nextCursor",
+            "'page 2' contains spaces".to_string()
+        )]
+    );
+}
+
+/// Test that all failures are reported at once
+#[test]
+fn test_all_failures_collected() {
+    let value = Listing {
+        total: 1,
+        items: Vec::new(),
+        page_size: 0,
+        cursor: String::new(),
+    };
+    assert_eq!(
+        failures(&value),
+        [
+            failure("/items", count_mismatch_error("total", 1, 0)),
+            failure("/items", empty_field_error("items")),
+            failure("/page_size", below_minimum_error("page_size", 0, 1)),
+            failure("/nextCursor", empty_field_error("cursor")),
+        ]
+    );
+}
+
+/// Test that pointers follow the container's rename_all rule and field renames
+#[test]
+fn test_rename_all_pointers() {
+    let value = SearchPage {
+        session_id: String::new(),
+        max_results: 5000,
+        found_files: Vec::new(),
+    };
+    assert_eq!(
+        failures(&value),
+        [
+            failure("/sessionId", empty_field_error("session_id")),
+            failure(
+                "/maxResults",
+                above_maximum_error("max_results", 5000, 1000)
+            ),
+            failure("/matches", empty_field_error("found_files")),
+        ]
+    );
+
+    let value = Headers {
+        content_type: String::new(),
+    };
+    assert_eq!(
+        failures(&value),
+        [failure("/CONTENT-TYPE", empty_field_error("content_type"))]
+    );
+}
+
+/// Test that nested structs report failures under their JSON pointer
+#[test]
+fn test_nested_response_types() {
+    let user = |id: u64, login: &str| json!({"id": id, "login": login});
+    let issue = |user| {
+        json!({
+            "id": 1, "number": 1, "title": "t", "state": "open",
+            "user": user, "assignees": [],
+            "html_url": null, "created_at": "", "updated_at": "",
+        })
+    };
+    // Bad nested values parse, so validation reports all of them together
+    let response: GitHubIssuesResponse = serde_json::from_value(json!({
+        "count": 3,
+        "issues": [issue(user(1, "ok")), issue(user(0, ""))],
+    }))
+    .expect("Failed to deserialize");
+
+    let errors = response.validate().expect_err("Response should be invalid");
+    let paths: Vec<_> = errors.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        ["/issues", "/issues/1/user/id", "/issues/1/user/login"]
+    );
+    assert_eq!(
+        errors.to_string(),
+        "/issues: count field value (3) does not match actual length (2); \
+         /issues/1/user/id: id value (0) is less than minimum (1); \
+         /issues/1/user/login: login cannot be empty"
+    );
+}
+
+/// Test that derived response types keep their hand-written invariants
+#[test]
+fn test_response_types() {
+    let response: SpawnClaudeAgentResponse = serde_json::from_value(json!({
+        "session_ids": ["a"],
+        "worker_count": 2,
+    }))
+    .expect("Failed to deserialize");
+    assert_eq!(
+        failures(&response),
+        [failure(
+            "/session_ids",
+            "worker_count field value (2) does not match actual length (1)".to_string()
+        )]
+    );
+}